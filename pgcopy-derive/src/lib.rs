@@ -0,0 +1,223 @@
+//! Derive macros for the [pgcopy](https://docs.rs/pgcopy) crate.
+//!
+//! Do not depend on this crate directly, enable `derive` feature of the `pgcopy` crate instead.
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, Attribute, Data, DeriveInput, Fields, LitStr};
+
+/// Maximum enum label length in bytes, as limited by server `NAMEDATALEN` constant.
+const MAX_LABEL_LEN: usize = 63;
+
+/// Derives `pgcopy::types::Enum` trait implementation for the fieldless enum.
+///
+/// Each variant is written as a label equal to its name, unless renamed with
+/// `#[pgcopy(rename = "...")]` variant attribute or `#[pgcopy(rename_all = "...")]` enum attribute.
+///
+/// Supported `rename_all` values are `lowercase`, `UPPERCASE`, `PascalCase`, `camelCase`,
+/// `snake_case`, `SCREAMING_SNAKE_CASE`, `kebab-case` and `SCREAMING-KEBAB-CASE`.
+///
+/// ```edition2018
+/// use pgcopy::PgEnum;
+///
+/// // CREATE TYPE order_status AS ENUM ('pending', 'in_progress', 'done');
+/// #[derive(PgEnum)]
+/// #[pgcopy(rename_all = "snake_case")]
+/// enum OrderStatus {
+///     Pending,
+///     InProgress,
+///     #[pgcopy(rename = "done")]
+///     Completed,
+/// }
+///
+/// let mut encoder = pgcopy::Encoder::new(vec![]);
+/// encoder.write_enum(OrderStatus::InProgress).unwrap();
+/// ```
+///
+/// Derive also adds `as_label(&self) -> &'static str` method returning the variant label.
+///
+/// Enum type OID is assigned by server when the type is created, so derived enums can not be
+/// array elements or composite fields on their own. Write their labels instead, with the enum type OID
+/// looked up in advance, for example, with `SELECT 'order_status'::regtype::oid` query:
+///
+/// ```edition2018
+/// # use pgcopy::PgEnum;
+/// use pgcopy::types::{PgArray, PgComposite};
+///
+/// # #[derive(PgEnum)]
+/// # #[pgcopy(rename_all = "snake_case")]
+/// # enum OrderStatus {
+/// #     Pending,
+/// #     InProgress,
+/// # }
+/// # let order_status_oid = 16385;
+/// let statuses = [OrderStatus::Pending, OrderStatus::InProgress];
+/// let labels: Vec<&str> = statuses.iter().map(OrderStatus::as_label).collect();
+///
+/// let mut encoder = pgcopy::Encoder::new(vec![]);
+/// // order_status[]
+/// encoder.write_array(PgArray::new(&labels).with_element_oid(order_status_oid)).unwrap();
+///
+/// // CREATE TYPE order_line AS (status order_status, amount integer);
+/// let mut line = PgComposite::new();
+/// line.field_with_oid(order_status_oid, OrderStatus::Pending.as_label()).unwrap()
+///     .field(3i32).unwrap();
+/// encoder.write_composite(&line).unwrap();
+/// ```
+#[proc_macro_derive(PgEnum, attributes(pgcopy))]
+pub fn derive_pg_enum(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand_pg_enum(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand_pg_enum(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let data = match input.data {
+        Data::Enum(ref data) => data,
+        _ => return Err(syn::Error::new_spanned(&input.ident, "PgEnum can be derived for enums only")),
+    };
+
+    let rename_all = match parse_attributes(&input.attrs, "rename_all")? {
+        Some(rule) => Some(RenameRule::from_lit(&rule)?),
+        None => None,
+    };
+
+    let mut arms = Vec::with_capacity(data.variants.len());
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(syn::Error::new_spanned(variant, "PgEnum variants can not have fields"));
+        }
+        if parse_attributes(&variant.attrs, "rename_all")?.is_some() {
+            return Err(syn::Error::new_spanned(variant, "`rename_all` is allowed for enums only"));
+        }
+
+        let ident = &variant.ident;
+        let label = match parse_attributes(&variant.attrs, "rename")? {
+            Some(label) => label,
+            None => {
+                let name = ident.to_string();
+                let label = match rename_all {
+                    Some(rule) => rule.apply(&name),
+                    None => name,
+                };
+                LitStr::new(&label, ident.span())
+            },
+        };
+        if label.value().is_empty() || label.value().len() > MAX_LABEL_LEN {
+            return Err(syn::Error::new_spanned(
+                &label,
+                format!("enum label should be from 1 to {} bytes long", MAX_LABEL_LEN),
+            ));
+        }
+
+        arms.push(quote! {
+            Self::#ident => #label,
+        });
+    }
+    if parse_attributes(&input.attrs, "rename")?.is_some() {
+        return Err(syn::Error::new_spanned(&input.ident, "`rename` is allowed for enum variants only"));
+    }
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #ident #ty_generics #where_clause {
+            /// Returns enum label written for this variant.
+            pub fn as_label(&self) -> &'static str {
+                match *self {
+                    #(#arms)*
+                }
+            }
+        }
+
+        impl #impl_generics ::pgcopy::types::Enum for #ident #ty_generics #where_clause {
+            fn to_writer<W: ::std::io::Write>(&self, writer: &mut W) -> ::std::io::Result<()> {
+                ::pgcopy::types::Enum::to_writer(self.as_label(), writer)
+            }
+        }
+    })
+}
+
+/// Looks for the `#[pgcopy(key = "value")]` attribute value.
+///
+/// Fails on any unknown key, so typos are not silently ignored.
+fn parse_attributes(attrs: &[Attribute], key: &str) -> syn::Result<Option<LitStr>> {
+    let mut value = None;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("pgcopy")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident(key) {
+                value = Some(meta.value()?.parse::<LitStr>()?);
+                Ok(())
+            } else if meta.path.is_ident("rename") || meta.path.is_ident("rename_all") {
+                // Skip the value of the other known attribute
+                meta.value()?.parse::<LitStr>().map(|_| ())
+            } else {
+                Err(meta.error("unknown pgcopy attribute"))
+            }
+        })?;
+    }
+
+    Ok(value)
+}
+
+#[derive(Clone, Copy)]
+enum RenameRule {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    ScreamingKebab,
+}
+
+impl RenameRule {
+    fn from_lit(lit: &LitStr) -> syn::Result<RenameRule> {
+        match lit.value().as_str() {
+            "lowercase" => Ok(RenameRule::Lower),
+            "UPPERCASE" => Ok(RenameRule::Upper),
+            "PascalCase" => Ok(RenameRule::Pascal),
+            "camelCase" => Ok(RenameRule::Camel),
+            "snake_case" => Ok(RenameRule::Snake),
+            "SCREAMING_SNAKE_CASE" => Ok(RenameRule::ScreamingSnake),
+            "kebab-case" => Ok(RenameRule::Kebab),
+            "SCREAMING-KEBAB-CASE" => Ok(RenameRule::ScreamingKebab),
+            other => Err(syn::Error::new(lit.span(), format!("unknown rename_all rule `{}`", other))),
+        }
+    }
+
+    /// Applies rule to the variant name, which is expected to be in a `PascalCase` already.
+    fn apply(self, variant: &str) -> String {
+        match self {
+            RenameRule::Lower => variant.to_ascii_lowercase(),
+            RenameRule::Upper => variant.to_ascii_uppercase(),
+            RenameRule::Pascal => variant.to_string(),
+            RenameRule::Camel => {
+                let mut chars = variant.chars();
+                match chars.next() {
+                    Some(first) => first.to_lowercase().chain(chars).collect(),
+                    None => String::new(),
+                }
+            },
+            RenameRule::Snake => {
+                let mut snake = String::with_capacity(variant.len());
+                for (idx, ch) in variant.char_indices() {
+                    if idx > 0 && ch.is_uppercase() {
+                        snake.push('_');
+                    }
+                    snake.extend(ch.to_lowercase());
+                }
+                snake
+            },
+            RenameRule::ScreamingSnake => RenameRule::Snake.apply(variant).to_ascii_uppercase(),
+            RenameRule::Kebab => RenameRule::Snake.apply(variant).replace('_', "-"),
+            RenameRule::ScreamingKebab => RenameRule::ScreamingSnake.apply(variant).replace('_', "-"),
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use pgcopy::{Encoder, PgEnum};
+use pgcopy::types::PgArray;
+
+#[derive(PgEnum)]
+enum Mood {
+    Sad,
+    #[pgcopy(rename = "very happy")]
+    VeryHappy,
+}
+
+#[derive(PgEnum)]
+#[pgcopy(rename_all = "snake_case")]
+enum OrderStatus {
+    InProgress,
+    #[pgcopy(rename = "done")]
+    Completed,
+}
+
+#[derive(PgEnum)]
+#[pgcopy(rename_all = "SCREAMING-KEBAB-CASE")]
+enum Priority {
+    VeryHigh,
+}
+
+#[derive(PgEnum)]
+#[pgcopy(rename_all = "camelCase")]
+enum Direction {
+    NorthEast,
+}
+
+fn written<F: FnOnce(&mut Encoder<Vec<u8>>) -> std::io::Result<()>>(f: F) -> Vec<u8> {
+    let mut encoder = Encoder::new(vec![]);
+    f(&mut encoder).unwrap();
+
+    encoder.get_ref().clone()
+}
+
+fn label(label: &str) -> Vec<u8> {
+    let mut expected = (label.len() as i32).to_be_bytes().to_vec();
+    expected.extend_from_slice(label.as_bytes());
+
+    expected
+}
+
+#[test]
+fn variant_name() {
+    assert_eq!(label("Sad"), written(|encoder| encoder.write_enum(Mood::Sad)));
+}
+
+#[test]
+fn rename() {
+    assert_eq!(label("very happy"), written(|encoder| encoder.write_enum(&Mood::VeryHappy)));
+    assert_eq!(label("done"), written(|encoder| encoder.write_enum(OrderStatus::Completed)));
+}
+
+#[test]
+fn rename_all() {
+    assert_eq!(label("in_progress"), written(|encoder| encoder.write_enum(OrderStatus::InProgress)));
+    assert_eq!(label("VERY-HIGH"), written(|encoder| encoder.write_enum(Priority::VeryHigh)));
+    assert_eq!(label("northEast"), written(|encoder| encoder.write_enum(Direction::NorthEast)));
+}
+
+#[test]
+fn as_label() {
+    assert_eq!("very happy", Mood::VeryHappy.as_label());
+    assert_eq!("in_progress", OrderStatus::InProgress.as_label());
+}
+
+#[test]
+fn labels_array() {
+    let labels: Vec<&str> = [Mood::Sad, Mood::VeryHappy].iter().map(Mood::as_label).collect();
+    let written = written(|encoder| encoder.write_array(PgArray::new(&labels).with_element_oid(16385)));
+
+    let mut expected = vec![0x00, 0x00, 0x00, 0x29, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40, 0x01,
+                            0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x01];
+    expected.extend(label("Sad"));
+    expected.extend(label("very happy"));
+    assert_eq!(expected, written);
+}
@@ -1,4 +1,3 @@
-use std::i32;
 use std::io::{Write, Result};
 
 use byteorder::{WriteBytesExt, NetworkEndian};
@@ -90,6 +89,8 @@ impl<W> Encoder<W> where W: Write {
     }
 
     /// Writes `NULL` as a column value.
+    ///
+    /// Typed methods write `NULL` for the `None` values too, so `Option` values can be passed to them directly.
     pub fn write_null(&mut self) -> Result<()> {
         self.inner.write_i32::<NetworkEndian>(-1)
     }
@@ -101,65 +102,78 @@ impl<W> Encoder<W> where W: Write {
     // https://github.com/postgres/postgres/blob/master/src/backend/utils/adt/int8.c
 
     /// Writes `smallint` type value.
-    pub fn write_smallint(&mut self, value: i16) -> Result<()> {
-        self.inner.write_i32::<NetworkEndian>(2)?;
-        self.inner.write_i16::<NetworkEndian>(value)
+    ///
+    /// Both `i16` and `Option<i16>` values are accepted.
+    pub fn write_smallint<T: types::SmallInt>(&mut self, value: T) -> Result<()> {
+        value.to_writer(&mut self.inner)
     }
 
     /// Writes `int` type value.
-    pub fn write_int(&mut self, value: i32) -> Result<()> {
-        self.inner.write_i32::<NetworkEndian>(4)?;
-        self.inner.write_i32::<NetworkEndian>(value)
+    ///
+    /// Both `i32` and `Option<i32>` values are accepted.
+    pub fn write_int<T: types::Int>(&mut self, value: T) -> Result<()> {
+        value.to_writer(&mut self.inner)
     }
 
     /// Writes `bigint` type value.
-    pub fn write_bigint(&mut self, value: i64) -> Result<()> {
-        self.inner.write_i32::<NetworkEndian>(8)?;
-        self.inner.write_i64::<NetworkEndian>(value)
+    ///
+    /// Both `i64` and `Option<i64>` values are accepted.
+    pub fn write_bigint<T: types::BigInt>(&mut self, value: T) -> Result<()> {
+        value.to_writer(&mut self.inner)
     }
 
     // Arbitrary precision numbers
 
-    #[doc(hidden)]
-    pub fn write_numeric<T: types::Numeric>(&mut self, _value: T) -> Result<()> {
-        unimplemented!()
+    /// Writes `numeric` type value.
+    ///
+    /// Same method should be used for `decimal` columns, as it is an alias for `numeric` type.
+    ///
+    /// See [Numeric](types/trait.Numeric.html) type implementors for available options here.
+    pub fn write_numeric<T: types::Numeric>(&mut self, value: T) -> Result<()> {
+        value.to_writer(&mut self.inner)
     }
 
     // Floating-point types
 
     /// Writes `real` type value.
-    pub fn write_real(&mut self, value: f32) -> Result<()> {
-        self.inner.write_i32::<NetworkEndian>(4)?;
-        self.inner.write_f32::<NetworkEndian>(value)
+    ///
+    /// Both `f32` and `Option<f32>` values are accepted.
+    pub fn write_real<T: types::Real>(&mut self, value: T) -> Result<()> {
+        value.to_writer(&mut self.inner)
     }
 
     /// Writes `double precision` type value.
-    pub fn write_double(&mut self, value: f64) -> Result<()> {
-        self.inner.write_i32::<NetworkEndian>(8)?;
-        self.inner.write_f64::<NetworkEndian>(value)
+    ///
+    /// Both `f64` and `Option<f64>` values are accepted.
+    pub fn write_double<T: types::Double>(&mut self, value: T) -> Result<()> {
+        value.to_writer(&mut self.inner)
     }
 
-    // TODO: Monetary types
+    // Monetary types
+    /// Writes `money` type value.
+    ///
+    /// See [Money](types/trait.Money.html) type implementors for available options here.
+    pub fn write_money<T: types::Money>(&mut self, value: T) -> Result<()> {
+        value.to_writer(&mut self.inner)
+    }
 
     // Character types
     /// Writes character type value.
     ///
     /// Any of `character varying(n)`, `character(n)` or `text` column type should be handled by this method.
-    pub fn write_str<T: AsRef<str>>(&mut self, value: T) -> Result<()> {
-        self.write_bytea(value.as_ref().as_bytes())
+    ///
+    /// See [Text](types/trait.Text.html) type implementors for available options here.
+    pub fn write_str<T: types::Text>(&mut self, value: T) -> Result<()> {
+        value.to_writer(&mut self.inner)
     }
 
     // Binary Data types
 
     /// Writes `bytea` type value.
-    pub fn write_bytea<T: AsRef<[u8]>>(&mut self, value: T) -> Result<()> {
-        let bytes = value.as_ref();
-        debug_assert!(bytes.len() < i32::MAX as usize);
-
-        self.inner.write_i32::<NetworkEndian>(bytes.len() as i32)?;
-        self.inner.write_all(bytes)?;
-
-        Ok(())
+    ///
+    /// See [Bytea](types/trait.Bytea.html) type implementors for available options here.
+    pub fn write_bytea<T: types::Bytea>(&mut self, value: T) -> Result<()> {
+        value.to_writer(&mut self.inner)
     }
 
     // Date/Time types
@@ -191,30 +205,114 @@ impl<W> Encoder<W> where W: Write {
         value.to_writer(&mut self.inner)
     }
 
-    #[doc(hidden)]
-    pub fn write_interval<T: types::Interval>(&mut self, _value: T) -> Result<()> {
-        unimplemented!()
+    /// Writes `time with time zone` type value.
+    ///
+    /// See [TimeWithTimeZone](types/trait.TimeWithTimeZone.html) type implementors for available options here.
+    pub fn write_time_with_time_zone<T: types::TimeWithTimeZone>(&mut self, value: T) -> Result<()> {
+        value.to_writer(&mut self.inner)
+    }
+
+    /// Writes `interval` type value.
+    ///
+    /// See [Interval](types/trait.Interval.html) type implementors for available options here.
+    pub fn write_interval<T: types::Interval>(&mut self, value: T) -> Result<()> {
+        value.to_writer(&mut self.inner)
     }
 
     // Boolean type
     /// Writes `bool` type value.
-    pub fn write_bool<T: Into<bool>>(&mut self, value: T) -> Result<()> {
-        self.inner.write_i32::<NetworkEndian>(1)?;
-        self.inner.write_i8(value.into() as i8)
+    ///
+    /// Both `bool` and `Option<bool>` values are accepted.
+    pub fn write_bool<T: types::Bool>(&mut self, value: T) -> Result<()> {
+        value.to_writer(&mut self.inner)
+    }
+
+    // Enumerated Types
+    /// Writes enumerated (`enum`) type value.
+    ///
+    /// See [Enum](types/trait.Enum.html) type implementors for available options here.
+    pub fn write_enum<T: types::Enum>(&mut self, value: T) -> Result<()> {
+        value.to_writer(&mut self.inner)
+    }
+
+    // Geometric Types
+    /// Writes `point` type value.
+    ///
+    /// See [Point](types/trait.Point.html) type implementors for available options here.
+    pub fn write_point<T: types::Point>(&mut self, value: T) -> Result<()> {
+        value.to_writer(&mut self.inner)
+    }
+
+    /// Writes `line` type value.
+    ///
+    /// See [Line](types/trait.Line.html) type implementors for available options here.
+    pub fn write_line<T: types::Line>(&mut self, value: T) -> Result<()> {
+        value.to_writer(&mut self.inner)
+    }
+
+    /// Writes `lseg` type value.
+    ///
+    /// See [LineSegment](types/trait.LineSegment.html) type implementors for available options here.
+    pub fn write_lseg<T: types::LineSegment>(&mut self, value: T) -> Result<()> {
+        value.to_writer(&mut self.inner)
+    }
+
+    /// Writes `box` type value.
+    ///
+    /// See [Rectangle](types/trait.Rectangle.html) type implementors for available options here.
+    pub fn write_box<T: types::Rectangle>(&mut self, value: T) -> Result<()> {
+        value.to_writer(&mut self.inner)
     }
 
-    // TODO: Enumerated Types
-    // TODO: Geometric Types
+    /// Writes `path` type value.
+    ///
+    /// See [Path](types/trait.Path.html) type implementors for available options here.
+    pub fn write_path<T: types::Path>(&mut self, value: T) -> Result<()> {
+        value.to_writer(&mut self.inner)
+    }
+
+    /// Writes `polygon` type value.
+    ///
+    /// See [Polygon](types/trait.Polygon.html) type implementors for available options here.
+    pub fn write_polygon<T: types::Polygon>(&mut self, value: T) -> Result<()> {
+        value.to_writer(&mut self.inner)
+    }
+
+    /// Writes `circle` type value.
+    ///
+    /// See [Circle](types/trait.Circle.html) type implementors for available options here.
+    pub fn write_circle<T: types::Circle>(&mut self, value: T) -> Result<()> {
+        value.to_writer(&mut self.inner)
+    }
+
+    // PostGIS Types
+    /// Writes PostGIS `geometry` type value.
+    ///
+    /// See [Geometry](types/trait.Geometry.html) type implementors for available options here.
+    pub fn write_geometry<T: types::Geometry>(&mut self, value: T) -> Result<()> {
+        value.to_writer(&mut self.inner)
+    }
+
+    /// Writes PostGIS `geography` type value.
+    ///
+    /// See [Geometry](types/trait.Geometry.html) type implementors for available options here.
+    pub fn write_geography<T: types::Geometry>(&mut self, value: T) -> Result<()> {
+        value.to_writer(&mut self.inner)
+    }
 
     // Network Address Types
-    #[doc(hidden)]
-    pub fn write_cidr<T: types::Cidr>(&mut self, _value: T) -> Result<()> {
-        unimplemented!()
+    /// Writes `cidr` type value.
+    ///
+    /// See [Cidr](types/trait.Cidr.html) type implementors for available options here.
+    pub fn write_cidr<T: types::Cidr>(&mut self, value: T) -> Result<()> {
+        value.to_writer(&mut self.inner)
     }
 
-    #[doc(hidden)]
-    pub fn write_inet<T: types::Inet>(&mut self, _value: T) -> Result<()> {
-        unimplemented!()
+    /// Writes `inet` type value.
+    ///
+    /// See [Inet](types/trait.Inet.html) type implementors for available options here.
+    pub fn write_inet<T: types::Inet>(&mut self, value: T) -> Result<()> {
+        value.to_writer(&mut self.inner)
     }
 
     /// Writes `macaddr` type value.
@@ -231,8 +329,35 @@ impl<W> Encoder<W> where W: Write {
         value.to_writer(&mut self.inner)
     }
 
-    // TODO: Bit String Types
-    // TODO: Text Search Types
+    // Bit String Types
+    /// Writes `bit` type value.
+    ///
+    /// See [Bit](types/trait.Bit.html) type implementors for available options here.
+    pub fn write_bit<T: types::Bit>(&mut self, value: T) -> Result<()> {
+        value.to_writer(&mut self.inner)
+    }
+
+    /// Writes `bit varying` type value.
+    ///
+    /// See [VarBit](types/trait.VarBit.html) type implementors for available options here.
+    pub fn write_varbit<T: types::VarBit>(&mut self, value: T) -> Result<()> {
+        value.to_writer(&mut self.inner)
+    }
+
+    // Text Search Types
+    /// Writes `tsvector` type value.
+    ///
+    /// See [TsVector](types/trait.TsVector.html) type implementors for available options here.
+    pub fn write_tsvector<T: types::TsVector>(&mut self, value: T) -> Result<()> {
+        value.to_writer(&mut self.inner)
+    }
+
+    /// Writes `tsquery` type value.
+    ///
+    /// See [TsQuery](types/trait.TsQuery.html) type implementors for available options here.
+    pub fn write_tsquery<T: types::TsQuery>(&mut self, value: T) -> Result<()> {
+        value.to_writer(&mut self.inner)
+    }
 
     // UUID Type
     /// Writes `uuid` type value.
@@ -243,25 +368,63 @@ impl<W> Encoder<W> where W: Write {
     }
 
     // XML Type
-    #[doc(hidden)]
-    pub fn write_xml<T: types::Xml>(&mut self, _value: T) -> Result<()> {
-        unimplemented!()
+    /// Writes `xml` type value.
+    ///
+    /// See [Xml](types/trait.Xml.html) type implementors for available options here.
+    pub fn write_xml<T: types::Xml>(&mut self, value: T) -> Result<()> {
+        value.to_writer(&mut self.inner)
     }
 
     // JSON Types
-    #[doc(hidden)]
-    pub fn write_json<T: types::Json>(&mut self, _value: T) -> Result<()> {
-        unimplemented!()
+    /// Writes `json` type value.
+    ///
+    /// See [Json](types/trait.Json.html) type implementors for available options here.
+    pub fn write_json<T: types::Json>(&mut self, value: T) -> Result<()> {
+        value.to_writer(&mut self.inner)
+    }
+
+    /// Writes `jsonb` type value.
+    ///
+    /// See [Jsonb](types/trait.Jsonb.html) type implementors for available options here.
+    pub fn write_jsonb<T: types::Jsonb>(&mut self, value: T) -> Result<()> {
+        value.to_writer(&mut self.inner)
+    }
+
+    // Arrays
+    /// Writes array type value.
+    ///
+    /// Any array element type implementing [Element](types/trait.Element.html) trait is supported.
+    ///
+    /// See [Array](types/trait.Array.html) type implementors for available options here.
+    pub fn write_array<T: types::Array>(&mut self, value: T) -> Result<()> {
+        value.to_writer(&mut self.inner)
+    }
+
+    // Composite Types
+    /// Writes composite type value.
+    ///
+    /// See [Composite](types/trait.Composite.html) type implementors for available options here.
+    pub fn write_composite<T: types::Composite>(&mut self, value: T) -> Result<()> {
+        value.to_writer(&mut self.inner)
     }
 
-    #[doc(hidden)]
-    pub fn write_jsonb<T: types::Jsonb>(&mut self, _value: T) -> Result<()> {
-        unimplemented!()
+    // Range Types
+    /// Writes range type value.
+    ///
+    /// Any of `int4range`, `int8range`, `numrange`, `tsrange`, `tstzrange` or `daterange` column type
+    /// should be handled by this method, as long as bounds are of the matching type.
+    ///
+    /// See [Range](types/trait.Range.html) type implementors for available options here.
+    pub fn write_range<T: types::Range>(&mut self, value: T) -> Result<()> {
+        value.to_writer(&mut self.inner)
     }
 
-    // TODO: Arrays
-    // TODO: Composite Types
-    // TODO: Range Types
+    /// Writes multirange type value.
+    ///
+    /// See [Multirange](types/trait.Multirange.html) type implementors for available options here.
+    pub fn write_multirange<T: types::Multirange>(&mut self, value: T) -> Result<()> {
+        value.to_writer(&mut self.inner)
+    }
 }
 
 #[macro_use]
@@ -0,0 +1,241 @@
+use std::time::{Duration, UNIX_EPOCH};
+
+use crate::types::{PgArray, PgTimestamp, PgJsonb, PgPoint, PgCircle, PgRange, PgMultirange, PgNumeric, Dimension, oid};
+
+assert_write!(nullable_array, write_array,
+    vec![Some(1), None, Some(3)],
+    vec![0x00, 0x00, 0x00, 0x28, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x17,
+         0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x01,
+         0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x03]
+);
+
+assert_write!(empty_array, write_array,
+    Vec::<i64>::new(),
+    vec![0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x14]
+);
+
+assert_write!(multidimensional_array, write_array,
+    PgArray::new(&["a", "b", "c", "d"]).with_dimensions(&[Dimension::new(2, 0), Dimension::new(2, 1)]),
+    vec![0x00, 0x00, 0x00, 0x30, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x19,
+         0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x01,
+         0x00, 0x00, 0x00, 0x01, 0x61, 0x00, 0x00, 0x00, 0x01, 0x62, 0x00, 0x00, 0x00, 0x01, 0x63, 0x00,
+         0x00, 0x00, 0x01, 0x64]
+);
+
+assert_write!(element_oid, write_array,
+    PgArray::new(&["a".to_string()]).with_element_oid(oid::VARCHAR),
+    vec![0x00, 0x00, 0x00, 0x19, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x13,
+         0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x61]
+);
+
+assert_write!(infinite_timestamps_array, write_array,
+    vec![PgTimestamp::Value(UNIX_EPOCH + Duration::from_secs(1_548_584_880)), PgTimestamp::Infinity],
+    vec![0x00, 0x00, 0x00, 0x2c, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0xa0,
+         0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x08, 0x00, 0x02, 0x23, 0x6c,
+         0xc8, 0x75, 0x6c, 0x00, 0x00, 0x00, 0x00, 0x08, 0x7f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff]
+);
+
+assert_write!(null_array, write_array,
+    None::<Vec<i32>>,
+    vec![0xff, 0xff, 0xff, 0xff]
+);
+
+assert_write!(jsonb_array, write_array,
+    [PgJsonb("[]")],
+    vec![0x00, 0x00, 0x00, 0x1b, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0e, 0xda,
+         0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x03, 0x01, 0x5b, 0x5d]
+);
+
+assert_write!(point_array, write_array,
+    [PgPoint::new(1.0, 2.0)],
+    vec![0x00, 0x00, 0x00, 0x28, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x58,
+         0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x10, 0x3f, 0xf0, 0x00, 0x00,
+         0x00, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]
+);
+
+assert_write!(circle_array, write_array,
+    [PgCircle::new(PgPoint::new(1.0, 2.0), 3.0)],
+    vec![0x00, 0x00, 0x00, 0x30, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xce,
+         0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x18, 0x3f, 0xf0, 0x00, 0x00,
+         0x00, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40, 0x08, 0x00, 0x00,
+         0x00, 0x00, 0x00, 0x00]
+);
+
+assert_write!(range_array, write_array,
+    vec![PgRange::from(1..10), PgRange::Empty],
+    vec![0x00, 0x00, 0x00, 0x2e, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0f, 0x40,
+         0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x11, 0x02, 0x00, 0x00, 0x00,
+         0x04, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00,
+         0x01, 0x01]
+);
+
+assert_write!(multirange_array, write_array,
+    vec![PgMultirange::from(vec![PgRange::from(1..3)])],
+    vec![0x00, 0x00, 0x00, 0x31, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x11, 0x63,
+         0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x19, 0x00, 0x00, 0x00, 0x01,
+         0x00, 0x00, 0x00, 0x11, 0x02, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+         0x04, 0x00, 0x00, 0x00, 0x03]
+);
+
+assert_write!(numeric_array, write_array,
+    vec![PgNumeric("1"), PgNumeric("2.5")],
+    vec![0x00, 0x00, 0x00, 0x32, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x06, 0xa4,
+         0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x01, 0x00, 0x00,
+         0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00,
+         0x00, 0x01, 0x00, 0x02, 0x13, 0x88]
+);
+
+assert_write!(numrange_array, write_array,
+    vec![PgRange::from(PgNumeric(1)..PgNumeric(10))],
+    vec![0x00, 0x00, 0x00, 0x35, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0f, 0x42,
+         0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x1d, 0x02, 0x00, 0x00, 0x00,
+         0x0a, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x0a, 0x00,
+         0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0a]
+);
+
+#[test]
+fn dimensions_mismatch() {
+    let mut encoder = crate::Encoder::new(vec![]);
+    let array = PgArray::new(&[1, 2, 3]).with_dimensions(&[Dimension::new(2, 1), Dimension::new(2, 1)]);
+
+    assert!(encoder.write_array(array).is_err());
+    assert!(encoder.get_ref().is_empty());
+}
+
+#[test]
+fn missing_dimensions() {
+    let mut encoder = crate::Encoder::new(vec![]);
+    let array = PgArray::new(&[1]).with_dimensions(&[]);
+
+    assert!(encoder.write_array(array).is_err());
+    assert!(encoder.get_ref().is_empty());
+}
+
+#[cfg(feature = "with-uuid")]
+mod with_uuid {
+    use std::str::FromStr;
+
+    use uuid::Uuid;
+
+    assert_write!(uuid_array, write_array,
+        [Uuid::from_str("1d662762-2010-11e9-ad8b-c869cdb5cd46").ok(), None],
+        vec![0x00, 0x00, 0x00, 0x2c, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x0b, 0x86,
+             0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x10, 0x1d, 0x66, 0x27, 0x62,
+             0x20, 0x10, 0x11, 0xe9, 0xad, 0x8b, 0xc8, 0x69, 0xcd, 0xb5, 0xcd, 0x46, 0xff, 0xff, 0xff, 0xff]
+    );
+}
+
+#[cfg(feature = "with-chrono")]
+mod with_chrono {
+    use chrono::{FixedOffset, NaiveTime};
+
+    assert_write!(timetz_array, write_array,
+        vec![(NaiveTime::from_hms_opt(10, 0, 0).unwrap(), FixedOffset::east_opt(3 * 60 * 60).unwrap())],
+        vec![0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0xf2,
+             0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x08,
+             0x61, 0xc4, 0x68, 0x00, 0xff, 0xff, 0xd5, 0xd0]
+    );
+}
+
+#[cfg(feature = "with-time")]
+mod with_time {
+    use time::{Time, UtcOffset};
+
+    assert_write!(timetz_array, write_array,
+        vec![(Time::from_hms(10, 0, 0).unwrap(), UtcOffset::from_hms(3, 0, 0).unwrap())],
+        vec![0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0xf2,
+             0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x08,
+             0x61, 0xc4, 0x68, 0x00, 0xff, 0xff, 0xd5, 0xd0]
+    );
+}
+
+#[cfg(feature = "with-jiff")]
+mod with_jiff {
+    use jiff::civil::Time;
+    use jiff::tz::Offset;
+
+    assert_write!(timetz_array, write_array,
+        vec![(Time::constant(10, 0, 0, 0), Offset::constant(3))],
+        vec![0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0xf2,
+             0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x08,
+             0x61, 0xc4, 0x68, 0x00, 0xff, 0xff, 0xd5, 0xd0]
+    );
+}
+
+#[cfg(feature = "with-ipnetwork")]
+mod with_ipnetwork {
+    use std::net::Ipv4Addr;
+
+    use ipnetwork::Ipv4Network;
+
+    assert_write!(inet_array, write_array,
+        vec![Ipv4Network::new(Ipv4Addr::new(10, 0, 0, 0), 8).unwrap()],
+        vec![0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x65,
+             0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x08, 0x02, 0x08, 0x00, 0x04,
+             0x0a, 0x00, 0x00, 0x00]
+    );
+}
+
+#[cfg(feature = "with-ipnet")]
+mod with_ipnet {
+    use std::net::Ipv6Addr;
+
+    use ipnet::Ipv6Net;
+
+    assert_write!(inet_array, write_array,
+        vec![Ipv6Net::new(Ipv6Addr::LOCALHOST, 64).unwrap()],
+        vec![0x00, 0x00, 0x00, 0x2c, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x65,
+             0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x14, 0x03, 0x40, 0x00, 0x10,
+             0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01]
+    );
+}
+
+#[cfg(feature = "with-geo-types")]
+mod with_geo_types {
+    use geo_types::point;
+
+    assert_write!(point_array, write_array,
+        vec![point!(x: 1.0, y: 2.0)],
+        vec![0x00, 0x00, 0x00, 0x28, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x58,
+             0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x10, 0x3f, 0xf0, 0x00, 0x00,
+             0x00, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]
+    );
+}
+
+#[cfg(feature = "with-serde_json")]
+mod with_serde_json {
+    use serde_json::json;
+
+    use crate::types::PgJsonb;
+
+    assert_write!(json_value_array, write_array,
+        vec![json!([1])],
+        vec![0x00, 0x00, 0x00, 0x1b, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x72,
+             0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x03, 0x5b, 0x31, 0x5d]
+    );
+
+    assert_write!(jsonb_value_array, write_array,
+        vec![PgJsonb(json!({}))],
+        vec![0x00, 0x00, 0x00, 0x1b, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0e, 0xda,
+             0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x03, 0x01, 0x7b, 0x7d]
+    );
+}
+
+#[cfg(feature = "with-quick-xml")]
+mod with_quick_xml {
+    use crate::types::PgCheckedXml;
+
+    assert_write!(xml_array, write_array,
+        [PgCheckedXml("<a/>")],
+        vec![0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x8e,
+             0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x04, 0x3c, 0x61, 0x2f, 0x3e]
+    );
+
+    #[test]
+    fn malformed_xml_array() {
+        let mut encoder = crate::Encoder::new(vec![]);
+
+        assert!(encoder.write_array([PgCheckedXml("<a>")]).is_err());
+        assert!(encoder.get_ref().is_empty());
+    }
+}
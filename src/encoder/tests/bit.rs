@@ -0,0 +1,45 @@
+use crate::types::PgBitString;
+
+assert_write!(bools, write_varbit,
+    &[true, false, true, true, false][..],
+    vec![0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0xb0]
+);
+
+assert_write!(bools_array, write_bit,
+    [true, false, true, true, false, false, true, true, true],
+    vec![0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x09, 0xb3, 0x80]
+);
+
+assert_write!(empty, write_varbit,
+    Vec::<bool>::new(),
+    vec![0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00]
+);
+
+assert_write!(bit_string, write_varbit,
+    PgBitString::new(8, vec![0xff]),
+    vec![0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x08, 0xff]
+);
+
+// Padding bits are zeroed
+assert_write!(bit_string_padding, write_bit,
+    &PgBitString::new(5, vec![0xb7]),
+    vec![0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0xb0]
+);
+
+#[test]
+fn bit_string_length_mismatch() {
+    let mut encoder = crate::Encoder::new(vec![]);
+
+    assert!(encoder.write_varbit(PgBitString::new(9, vec![0xff])).is_err());
+    assert!(encoder.get_ref().is_empty());
+}
+
+#[cfg(feature = "with-bit-vec")]
+mod with_bit_vec {
+    use bit_vec::BitVec;
+
+    assert_write!(bit_vec, write_varbit,
+        BitVec::from_fn(9, |idx| [0, 2, 3, 6, 7, 8].contains(&idx)),
+        vec![0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x09, 0xb3, 0x80]
+    );
+}
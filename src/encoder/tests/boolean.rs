@@ -1,3 +1,7 @@
 assert_bool!(bool_true, true, vec![0x00, 0x00, 0x00, 0x01, 0x01]);
 
 assert_bool!(bool_false, false, vec![0x00, 0x00, 0x00, 0x01, 0x00]);
+
+assert_bool!(bool_some, Some(true), vec![0x00, 0x00, 0x00, 0x01, 0x01]);
+
+assert_bool!(bool_none, None, vec![0xff, 0xff, 0xff, 0xff]);
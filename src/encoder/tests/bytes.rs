@@ -1,3 +1,19 @@
 assert_write!(bytes, write_bytea,
     [0xde, 0xad, 0xbe, 0xef],
     vec![0x00, 0x00, 0x00, 0x04, 0xde, 0xad, 0xbe, 0xef]);
+
+assert_write!(bytes_vec, write_bytea,
+    vec![0xde, 0xad, 0xbe, 0xef],
+    vec![0x00, 0x00, 0x00, 0x04, 0xde, 0xad, 0xbe, 0xef]);
+
+assert_write!(null_bytes, write_bytea,
+    None::<&[u8]>,
+    vec![0xff, 0xff, 0xff, 0xff]);
+
+assert_write!(shared_bytes, write_bytea,
+    std::rc::Rc::<[u8]>::from(&[0xde, 0xad, 0xbe, 0xef][..]),
+    vec![0x00, 0x00, 0x00, 0x04, 0xde, 0xad, 0xbe, 0xef]);
+
+assert_write!(atomic_shared_bytes, write_bytea,
+    std::sync::Arc::<[u8]>::from(vec![0xde, 0xad, 0xbe, 0xef]),
+    vec![0x00, 0x00, 0x00, 0x04, 0xde, 0xad, 0xbe, 0xef]);
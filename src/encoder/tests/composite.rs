@@ -0,0 +1,50 @@
+use crate::types::{PgComposite, PgArray, PgRange, oid};
+
+// CREATE TYPE inventory_item AS (name text, supplier_id integer, price numeric);
+const INVENTORY_ITEM_OID: u32 = 16395;
+
+fn inventory_item(name: &str, supplier_id: i32) -> PgComposite {
+    let mut item = PgComposite::new();
+    item.field(name).unwrap()
+        .field(supplier_id).unwrap()
+        .field_with_oid(oid::NUMERIC, None::<i32>).unwrap();
+
+    item
+}
+
+assert_write!(composite, write_composite,
+    inventory_item("fuzzy dice", 42),
+    vec![0x00, 0x00, 0x00, 0x2a, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x19, 0x00, 0x00, 0x00, 0x0a,
+         0x66, 0x75, 0x7a, 0x7a, 0x79, 0x20, 0x64, 0x69, 0x63, 0x65, 0x00, 0x00, 0x00, 0x17, 0x00, 0x00,
+         0x00, 0x04, 0x00, 0x00, 0x00, 0x2a, 0x00, 0x00, 0x06, 0xa4, 0xff, 0xff, 0xff, 0xff]
+);
+
+assert_write!(composite_array, write_array,
+    PgArray::new(&[Some(inventory_item("x", 1)), None]).with_element_oid(INVENTORY_ITEM_OID),
+    vec![0x00, 0x00, 0x00, 0x3d, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x40, 0x0b,
+         0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x03,
+         0x00, 0x00, 0x00, 0x19, 0x00, 0x00, 0x00, 0x01, 0x78, 0x00, 0x00, 0x00, 0x17, 0x00, 0x00, 0x00,
+         0x04, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x06, 0xa4, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+         0xff]
+);
+
+assert_write!(range_field, write_composite,
+    {
+        let mut composite = PgComposite::new();
+        composite.field(PgRange::from(1i64..)).unwrap();
+        composite
+    },
+    vec![0x00, 0x00, 0x00, 0x19, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x0f, 0x56, 0x00, 0x00, 0x00, 0x0d,
+         0x12, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01]
+);
+
+#[test]
+fn invalid_field() {
+    use std::time::Duration;
+
+    let mut composite = inventory_item("x", 1);
+    let expected = composite.clone();
+
+    assert!(composite.field(Duration::from_secs(u64::MAX)).is_err());
+    assert_eq!(expected, composite);
+}
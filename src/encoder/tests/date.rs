@@ -4,8 +4,74 @@ mod with_chrono {
 
     use chrono::NaiveDate;
 
+    use crate::types::{PgDate, OutOfRange};
+
     assert_write!(naive_date, write_date,
         NaiveDate::from_str("2019-01-27").unwrap(),
         vec![0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x1b, 0x36]
     );
+
+    assert_write!(min_date, write_date,
+        NaiveDate::from_ymd_opt(-4713, 11, 24).unwrap(),
+        vec![0x00, 0x00, 0x00, 0x04, 0xff, 0xda, 0x97, 0xa7]
+    );
+
+    assert_write!(far_future_date, write_date,
+        NaiveDate::from_ymd_opt(200_000, 1, 1).unwrap(),
+        vec![0x00, 0x00, 0x00, 0x04, 0x04, 0x4f, 0x7c, 0x3f]
+    );
+
+    #[test]
+    fn date_out_of_range() {
+        let mut encoder = crate::Encoder::new(vec![]);
+        let err = encoder.write_date(NaiveDate::from_ymd_opt(-4713, 11, 23).unwrap()).unwrap_err();
+
+        assert_eq!(err.get_ref().and_then(|inner| inner.downcast_ref()), Some(&OutOfRange::new("date")));
+        assert!(encoder.get_ref().is_empty());
+    }
+
+    assert_write!(finite, write_date,
+        PgDate::from(NaiveDate::from_str("2019-01-27").unwrap()),
+        vec![0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x1b, 0x36]
+    );
+
+    assert_write!(infinity, write_date,
+        PgDate::<NaiveDate>::or_infinity(None),
+        vec![0x00, 0x00, 0x00, 0x04, 0x7f, 0xff, 0xff, 0xff]
+    );
+
+    assert_write!(neg_infinity, write_date,
+        PgDate::<NaiveDate>::NegInfinity,
+        vec![0x00, 0x00, 0x00, 0x04, 0x80, 0x00, 0x00, 0x00]
+    );
+}
+
+#[cfg(feature = "with-time")]
+mod with_time {
+    use time::{Date, Month};
+
+    assert_write!(date, write_date,
+        Date::from_calendar_date(2019, Month::January, 27).unwrap(),
+        vec![0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x1b, 0x36]
+    );
+
+    assert_write!(date_before_epoch, write_date,
+        Date::from_calendar_date(1999, Month::December, 31).unwrap(),
+        vec![0x00, 0x00, 0x00, 0x04, 0xff, 0xff, 0xff, 0xff]
+    );
+}
+
+#[cfg(feature = "with-jiff")]
+mod with_jiff {
+    use jiff::civil::Date;
+
+    assert_write!(civil_date, write_date,
+        Date::constant(2019, 1, 27),
+        vec![0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x1b, 0x36]
+    );
+
+    assert_write!(civil_date_before_epoch, write_date,
+        Date::constant(1999, 12, 31),
+        vec![0x00, 0x00, 0x00, 0x04, 0xff, 0xff, 0xff, 0xff]
+    );
 }
@@ -0,0 +1,9 @@
+assert_write!(enum_str, write_enum,
+    "very happy",
+    vec![0x00, 0x00, 0x00, 0x0a, 0x76, 0x65, 0x72, 0x79, 0x20, 0x68, 0x61, 0x70, 0x70, 0x79]
+);
+
+assert_write!(enum_string, write_enum,
+    "very happy".to_string(),
+    vec![0x00, 0x00, 0x00, 0x0a, 0x76, 0x65, 0x72, 0x79, 0x20, 0x68, 0x61, 0x70, 0x70, 0x79]
+);
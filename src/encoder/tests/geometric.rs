@@ -0,0 +1,110 @@
+use crate::types::{PgPoint, PgLine, PgBox, PgPath, PgPolygon, PgCircle};
+
+assert_write!(point, write_point,
+    (1.5, -2.0),
+    vec![0x00, 0x00, 0x00, 0x10, 0x3f, 0xf8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xc0, 0x00, 0x00, 0x00,
+         0x00, 0x00, 0x00, 0x00]
+);
+
+assert_write!(line, write_line,
+    PgLine::new(1.0, -1.0, 0.0),
+    vec![0x00, 0x00, 0x00, 0x18, 0x3f, 0xf0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xbf, 0xf0, 0x00, 0x00,
+         0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]
+);
+
+assert_write!(lseg, write_lseg,
+    ((0.0, 0.0), (1.0, 1.0)),
+    vec![0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+         0x00, 0x00, 0x00, 0x00, 0x3f, 0xf0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3f, 0xf0, 0x00, 0x00,
+         0x00, 0x00, 0x00, 0x00]
+);
+
+assert_write!(pg_box, write_box,
+    PgBox::new(PgPoint::new(0.0, 1.0), PgPoint::new(2.0, 0.0)),
+    vec![0x00, 0x00, 0x00, 0x20, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3f, 0xf0, 0x00, 0x00,
+         0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+         0x00, 0x00, 0x00, 0x00]
+);
+
+assert_write!(path, write_path,
+    PgPath::open(vec![PgPoint::new(0.0, 0.0), PgPoint::new(1.0, 1.0)]),
+    vec![0x00, 0x00, 0x00, 0x25, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+         0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3f, 0xf0, 0x00, 0x00, 0x00, 0x00, 0x00,
+         0x00, 0x3f, 0xf0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]
+);
+
+assert_write!(polygon, write_polygon,
+    vec![(0.0, 0.0), (1.0, 1.0), (1.0, 0.0)],
+    vec![0x00, 0x00, 0x00, 0x34, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+         0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3f, 0xf0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+         0x3f, 0xf0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3f, 0xf0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+         0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]
+);
+
+assert_write!(circle, write_circle,
+    PgCircle::new(PgPoint::new(1.0, 1.0), 2.0),
+    vec![0x00, 0x00, 0x00, 0x18, 0x3f, 0xf0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3f, 0xf0, 0x00, 0x00,
+         0x00, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]
+);
+
+#[test]
+fn invalid_values() {
+    let mut encoder = crate::Encoder::new(vec![]);
+
+    assert!(encoder.write_line((0.0, 0.0, 1.0)).is_err());
+    assert!(encoder.write_polygon(PgPolygon::new(vec![])).is_err());
+    assert!(encoder.write_circle(((0.0, 0.0), -1.0)).is_err());
+    assert!(encoder.get_ref().is_empty());
+}
+
+#[cfg(feature = "with-geo-types")]
+mod with_geo_types {
+    use geo_types::{coord, line_string, point, polygon, Rect};
+
+    assert_write!(point, write_point,
+        point!(x: 1.5, y: -2.0),
+        vec![0x00, 0x00, 0x00, 0x10, 0x3f, 0xf8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xc0, 0x00, 0x00, 0x00,
+             0x00, 0x00, 0x00, 0x00]
+    );
+
+    assert_write!(rect, write_box,
+        Rect::new(coord! { x: 0.0, y: 1.0 }, coord! { x: 2.0, y: 0.0 }),
+        vec![0x00, 0x00, 0x00, 0x20, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3f, 0xf0, 0x00, 0x00,
+             0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+             0x00, 0x00, 0x00, 0x00]
+    );
+
+    assert_write!(open_line_string, write_path,
+        line_string![(x: 0.0, y: 0.0), (x: 1.0, y: 1.0)],
+        vec![0x00, 0x00, 0x00, 0x25, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+             0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3f, 0xf0, 0x00, 0x00, 0x00, 0x00, 0x00,
+             0x00, 0x3f, 0xf0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]
+    );
+
+    assert_write!(closed_line_string, write_path,
+        line_string![(x: 0.0, y: 0.0), (x: 1.0, y: 1.0), (x: 0.0, y: 0.0)],
+        vec![0x00, 0x00, 0x00, 0x25, 0x01, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+             0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3f, 0xf0, 0x00, 0x00, 0x00, 0x00, 0x00,
+             0x00, 0x3f, 0xf0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]
+    );
+
+    assert_write!(polygon, write_polygon,
+        polygon![(x: 0.0, y: 0.0), (x: 1.0, y: 1.0), (x: 1.0, y: 0.0)],
+        vec![0x00, 0x00, 0x00, 0x34, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+             0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3f, 0xf0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+             0x3f, 0xf0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3f, 0xf0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+             0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]
+    );
+
+    #[test]
+    fn polygon_with_interiors() {
+        let polygon = polygon!(
+            exterior: [(x: 0.0, y: 0.0), (x: 10.0, y: 0.0), (x: 10.0, y: 10.0), (x: 0.0, y: 10.0)],
+            interiors: [[(x: 1.0, y: 1.0), (x: 2.0, y: 1.0), (x: 2.0, y: 2.0)]],
+        );
+        let mut encoder = crate::Encoder::new(vec![]);
+
+        assert!(encoder.write_polygon(&polygon).is_err());
+        assert!(encoder.get_ref().is_empty());
+    }
+}
@@ -0,0 +1,135 @@
+use crate::types::{PgCoord, PgGeometry, PgSrid};
+
+assert_write!(point, write_geometry,
+    PgGeometry::Point(PgCoord::new(1.0, 2.0)),
+    vec![0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x00, 0x01, 0x3f, 0xf0, 0x00, 0x00, 0x00, 0x00, 0x00,
+         0x00, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]
+);
+
+assert_write!(point_srid, write_geography,
+    PgSrid::new(4326, PgGeometry::Point(PgCoord::new(1.0, 2.0))),
+    vec![0x00, 0x00, 0x00, 0x19, 0x00, 0x20, 0x00, 0x00, 0x01, 0x00, 0x00, 0x10, 0xe6, 0x3f, 0xf0, 0x00,
+         0x00, 0x00, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]
+);
+
+assert_write!(point_z, write_geometry,
+    PgGeometry::Point(PgCoord::xyz(1.0, 2.0, 3.0)),
+    vec![0x00, 0x00, 0x00, 0x1d, 0x00, 0x80, 0x00, 0x00, 0x01, 0x3f, 0xf0, 0x00, 0x00, 0x00, 0x00, 0x00,
+         0x00, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00,
+         0x00]
+);
+
+assert_write!(point_zm, write_geometry,
+    PgGeometry::Point(PgCoord::xyzm(1.0, 2.0, 3.0, 4.0)),
+    vec![0x00, 0x00, 0x00, 0x25, 0x00, 0xc0, 0x00, 0x00, 0x01, 0x3f, 0xf0, 0x00, 0x00, 0x00, 0x00, 0x00,
+         0x00, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00,
+         0x00, 0x40, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]
+);
+
+assert_write!(line_string_m, write_geometry,
+    PgGeometry::LineString(vec![PgCoord::xym(0.0, 0.0, 1.0), PgCoord::xym(1.0, 1.0, 2.0)]),
+    vec![0x00, 0x00, 0x00, 0x39, 0x00, 0x40, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00,
+         0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3f, 0xf0, 0x00,
+         0x00, 0x00, 0x00, 0x00, 0x00, 0x3f, 0xf0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3f, 0xf0, 0x00,
+         0x00, 0x00, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]
+);
+
+assert_write!(polygon, write_geometry,
+    &PgGeometry::Polygon(vec![vec![(0.0, 0.0).into(), (1.0, 0.0).into(), (0.0, 1.0).into(), (0.0, 0.0).into()]]),
+    vec![0x00, 0x00, 0x00, 0x4d, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+         0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+         0x00, 0x3f, 0xf0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+         0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3f, 0xf0, 0x00, 0x00, 0x00, 0x00, 0x00,
+         0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+         0x00]
+);
+
+assert_write!(multi_point, write_geometry,
+    PgSrid::new(4326, &PgGeometry::MultiPoint(vec![PgCoord::new(0.0, 0.0), PgCoord::new(1.0, 1.0)])),
+    vec![0x00, 0x00, 0x00, 0x37, 0x00, 0x20, 0x00, 0x00, 0x04, 0x00, 0x00, 0x10, 0xe6, 0x00, 0x00, 0x00,
+         0x02, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+         0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x3f, 0xf0, 0x00, 0x00, 0x00,
+         0x00, 0x00, 0x00, 0x3f, 0xf0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]
+);
+
+assert_write!(collection, write_geometry,
+    PgGeometry::GeometryCollection(vec![
+        PgGeometry::Point(PgCoord::xyz(0.0, 0.0, 0.0)),
+        PgGeometry::LineString(vec![PgCoord::xyz(0.0, 0.0, 0.0), PgCoord::xyz(1.0, 1.0, 1.0)]),
+    ]),
+    vec![0x00, 0x00, 0x00, 0x5f, 0x00, 0x80, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x02, 0x00, 0x80, 0x00,
+         0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+         0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x02, 0x00,
+         0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+         0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3f, 0xf0, 0x00, 0x00, 0x00,
+         0x00, 0x00, 0x00, 0x3f, 0xf0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3f, 0xf0, 0x00, 0x00, 0x00,
+         0x00, 0x00, 0x00]
+);
+
+assert_write!(empty_collection, write_geometry,
+    PgGeometry::GeometryCollection(vec![]),
+    vec![0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x00]
+);
+
+#[test]
+fn mixed_dimensions() {
+    let geometry = PgGeometry::LineString(vec![PgCoord::xyz(0.0, 0.0, 0.0), PgCoord::new(1.0, 1.0)]);
+    let mut encoder = crate::Encoder::new(vec![]);
+
+    assert!(encoder.write_geometry(geometry).is_err());
+    assert!(encoder.get_ref().is_empty());
+}
+
+#[test]
+fn single_point_line_string() {
+    let geometry = PgGeometry::LineString(vec![PgCoord::new(0.0, 0.0)]);
+    let mut encoder = crate::Encoder::new(vec![]);
+
+    assert!(encoder.write_geometry(geometry).is_err());
+    assert!(encoder.get_ref().is_empty());
+}
+
+#[test]
+fn non_closed_ring() {
+    let ring = vec![(0.0, 0.0).into(), (1.0, 0.0).into(), (0.0, 1.0).into(), (1.0, 1.0).into()];
+    let mut encoder = crate::Encoder::new(vec![]);
+
+    assert!(encoder.write_geometry(PgGeometry::Polygon(vec![ring])).is_err());
+    assert!(encoder.get_ref().is_empty());
+}
+
+#[cfg(feature = "with-geo-types")]
+mod with_geo_types {
+    use geo_types::{coord, point, polygon, Line, MultiPolygon};
+
+    use crate::types::PgSrid;
+
+    assert_write!(point, write_geometry,
+        point!(x: 1.0, y: 2.0),
+        vec![0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x00, 0x01, 0x3f, 0xf0, 0x00, 0x00, 0x00, 0x00, 0x00,
+             0x00, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]
+    );
+
+    assert_write!(line, write_geometry,
+        Line::new(coord! { x: 0.0, y: 0.0 }, coord! { x: 1.0, y: 1.0 }),
+        vec![0x00, 0x00, 0x00, 0x29, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00,
+             0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3f, 0xf0, 0x00,
+             0x00, 0x00, 0x00, 0x00, 0x00, 0x3f, 0xf0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]
+    );
+
+    assert_write!(multi_polygon_srid, write_geometry,
+        PgSrid::new(3857, MultiPolygon::new(vec![polygon![(x: 0.0, y: 0.0), (x: 1.0, y: 0.0), (x: 0.0, y: 1.0)]])),
+        vec![0x00, 0x00, 0x00, 0x5a, 0x00, 0x20, 0x00, 0x00, 0x06, 0x00, 0x00, 0x0f, 0x11, 0x00, 0x00, 0x00,
+             0x01, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00,
+             0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3f, 0xf0,
+             0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+             0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3f, 0xf0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+             0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]
+    );
+
+    assert_write!(geometry, write_geometry,
+        geo_types::Geometry::Point(point!(x: 1.0, y: 2.0)),
+        vec![0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x00, 0x01, 0x3f, 0xf0, 0x00, 0x00, 0x00, 0x00, 0x00,
+             0x00, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]
+    );
+}
@@ -0,0 +1,96 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+assert_write!(inet_ipv4, write_inet,
+    Ipv4Addr::new(10, 0, 0, 1),
+    vec![0x00, 0x00, 0x00, 0x08, 0x02, 0x20, 0x00, 0x04, 0x0a, 0x00, 0x00, 0x01]
+);
+
+assert_write!(inet_ipv6, write_inet,
+    IpAddr::V6(Ipv6Addr::new(0x2001, 0x0db8, 0, 0, 0, 0, 0, 1)),
+    vec![0x00, 0x00, 0x00, 0x14, 0x03, 0x80, 0x00, 0x10,
+         0x20, 0x01, 0x0d, 0xb8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01]
+);
+
+assert_write!(inet_prefix, write_inet,
+    (IpAddr::V4(Ipv4Addr::new(192, 168, 1, 5)), 24),
+    vec![0x00, 0x00, 0x00, 0x08, 0x02, 0x18, 0x00, 0x04, 0xc0, 0xa8, 0x01, 0x05]
+);
+
+assert_write!(cidr_ipv4, write_cidr,
+    (IpAddr::V4(Ipv4Addr::new(10, 1, 0, 0)), 16),
+    vec![0x00, 0x00, 0x00, 0x08, 0x02, 0x10, 0x01, 0x04, 0x0a, 0x01, 0x00, 0x00]
+);
+
+assert_write!(cidr_ipv6, write_cidr,
+    (IpAddr::V6(Ipv6Addr::new(0x2001, 0x0db8, 0, 0, 0, 0, 0, 0)), 32),
+    vec![0x00, 0x00, 0x00, 0x14, 0x03, 0x20, 0x01, 0x10,
+         0x20, 0x01, 0x0d, 0xb8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]
+);
+
+#[test]
+fn cidr_host_bits_set() {
+    let mut encoder = crate::Encoder::new(vec![]);
+
+    assert!(encoder.write_cidr((IpAddr::V4(Ipv4Addr::new(10, 1, 0, 1)), 16)).is_err());
+    assert!(encoder.write_cidr((IpAddr::V4(Ipv4Addr::new(10, 1, 0, 64)), 25)).is_err());
+    assert!(encoder.get_ref().is_empty());
+}
+
+#[test]
+fn invalid_netmask() {
+    let mut encoder = crate::Encoder::new(vec![]);
+
+    assert!(encoder.write_inet((IpAddr::V4(Ipv4Addr::new(10, 1, 0, 1)), 33)).is_err());
+    assert!(encoder.get_ref().is_empty());
+}
+
+#[cfg(feature = "with-ipnetwork")]
+mod with_ipnetwork {
+    use std::str::FromStr;
+
+    use ipnetwork::{IpNetwork, Ipv4Network};
+
+    assert_write!(inet, write_inet,
+        IpNetwork::from_str("192.168.1.5/24").unwrap(),
+        vec![0x00, 0x00, 0x00, 0x08, 0x02, 0x18, 0x00, 0x04, 0xc0, 0xa8, 0x01, 0x05]
+    );
+
+    assert_write!(cidr, write_cidr,
+        Ipv4Network::from_str("10.1.0.0/16").unwrap(),
+        vec![0x00, 0x00, 0x00, 0x08, 0x02, 0x10, 0x01, 0x04, 0x0a, 0x01, 0x00, 0x00]
+    );
+
+    #[test]
+    fn cidr_host_bits_set() {
+        let mut encoder = crate::Encoder::new(vec![]);
+
+        assert!(encoder.write_cidr(IpNetwork::from_str("10.1.0.1/16").unwrap()).is_err());
+        assert!(encoder.get_ref().is_empty());
+    }
+}
+
+#[cfg(feature = "with-ipnet")]
+mod with_ipnet {
+    use std::str::FromStr;
+
+    use ipnet::{IpNet, Ipv6Net};
+
+    assert_write!(inet, write_inet,
+        IpNet::from_str("192.168.1.5/24").unwrap(),
+        vec![0x00, 0x00, 0x00, 0x08, 0x02, 0x18, 0x00, 0x04, 0xc0, 0xa8, 0x01, 0x05]
+    );
+
+    assert_write!(cidr, write_cidr,
+        Ipv6Net::from_str("2001:db8::/32").unwrap(),
+        vec![0x00, 0x00, 0x00, 0x14, 0x03, 0x20, 0x01, 0x10,
+             0x20, 0x01, 0x0d, 0xb8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]
+    );
+
+    #[test]
+    fn cidr_host_bits_set() {
+        let mut encoder = crate::Encoder::new(vec![]);
+
+        assert!(encoder.write_cidr(IpNet::from_str("10.1.0.1/16").unwrap()).is_err());
+        assert!(encoder.get_ref().is_empty());
+    }
+}
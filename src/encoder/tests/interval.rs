@@ -0,0 +1,78 @@
+use std::time::Duration;
+
+use crate::types::PgInterval;
+
+assert_write!(pg_interval, write_interval,
+    PgInterval::new(14, 3, 14_706_000_007),
+    vec![0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x03, 0x6c, 0x8b, 0xc0, 0x87, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x0e]
+);
+
+assert_write!(negative_pg_interval, write_interval,
+    PgInterval::new(0, -3, -1_000_000),
+    vec![0x00, 0x00, 0x00, 0x10, 0xff, 0xff, 0xff, 0xff, 0xff, 0xf0, 0xbd, 0xc0, 0xff, 0xff, 0xff, 0xfd, 0x00, 0x00, 0x00, 0x00]
+);
+
+assert_write!(std_duration, write_interval,
+    Duration::from_nanos(500_000_999),
+    vec![0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x07, 0xa1, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]
+);
+
+#[test]
+fn std_duration_overflow() {
+    let mut encoder = crate::Encoder::new(vec![]);
+
+    assert!(encoder.write_interval(Duration::from_secs(u64::MAX)).is_err());
+    assert!(encoder.get_ref().is_empty());
+}
+
+#[cfg(feature = "with-chrono")]
+mod with_chrono {
+    use chrono::Duration;
+
+    assert_write!(chrono_duration, write_interval,
+        Duration::seconds(-90),
+        vec![0x00, 0x00, 0x00, 0x10, 0xff, 0xff, 0xff, 0xff, 0xfa, 0xa2, 0xb5, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]
+    );
+
+    #[test]
+    fn chrono_duration_overflow() {
+        let mut encoder = crate::Encoder::new(vec![]);
+
+        assert!(encoder.write_interval(Duration::MAX).is_err());
+        assert!(encoder.get_ref().is_empty());
+    }
+}
+
+#[cfg(feature = "with-time")]
+mod with_time {
+    use time::Duration;
+
+    assert_write!(time_duration, write_interval,
+        Duration::seconds(-90),
+        vec![0x00, 0x00, 0x00, 0x10, 0xff, 0xff, 0xff, 0xff, 0xfa, 0xa2, 0xb5, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]
+    );
+
+    #[test]
+    fn time_duration_overflow() {
+        let mut encoder = crate::Encoder::new(vec![]);
+
+        assert!(encoder.write_interval(Duration::MAX).is_err());
+        assert!(encoder.get_ref().is_empty());
+    }
+}
+
+#[cfg(feature = "with-jiff")]
+mod with_jiff {
+    use jiff::{Span, ToSpan};
+
+    // 1 year 2 mons 3 days 04:05:06.000007
+    assert_write!(span, write_interval,
+        1.year().months(2).days(3).hours(4).minutes(5).seconds(6).microseconds(7),
+        vec![0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x03, 0x6c, 0x8b, 0xc0, 0x87, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x0e]
+    );
+
+    assert_write!(negative_span, write_interval,
+        Span::new().weeks(-1).seconds(-90),
+        vec![0x00, 0x00, 0x00, 0x10, 0xff, 0xff, 0xff, 0xff, 0xfa, 0xa2, 0xb5, 0x80, 0xff, 0xff, 0xff, 0xf9, 0x00, 0x00, 0x00, 0x00]
+    );
+}
@@ -0,0 +1,59 @@
+assert_write!(json_str, write_json,
+    r#"{"a": [1, 2]}"#,
+    vec![0x00, 0x00, 0x00, 0x0d, 0x7b, 0x22, 0x61, 0x22, 0x3a, 0x20, 0x5b, 0x31, 0x2c, 0x20, 0x32, 0x5d, 0x7d]
+);
+
+assert_write!(jsonb_string, write_jsonb,
+    r#"{"a": [1, 2]}"#.to_string(),
+    vec![0x00, 0x00, 0x00, 0x0e, 0x01, 0x7b, 0x22, 0x61, 0x22, 0x3a, 0x20, 0x5b, 0x31, 0x2c, 0x20, 0x32, 0x5d, 0x7d]
+);
+
+#[cfg(feature = "with-serde_json")]
+mod with_serde_json {
+    use std::cell::Cell;
+    use std::collections::BTreeMap;
+
+    use serde::{Serialize, Serializer};
+
+    use serde_json::json;
+    use serde_json::value::RawValue;
+
+    use crate::types::PgJson;
+
+    assert_write!(json_value, write_json,
+        json!({"a": [1, 2]}),
+        vec![0x00, 0x00, 0x00, 0x0b, 0x7b, 0x22, 0x61, 0x22, 0x3a, 0x5b, 0x31, 0x2c, 0x32, 0x5d, 0x7d]
+    );
+
+    assert_write!(jsonb_value, write_jsonb,
+        &json!({"a": [1, 2]}),
+        vec![0x00, 0x00, 0x00, 0x0c, 0x01, 0x7b, 0x22, 0x61, 0x22, 0x3a, 0x5b, 0x31, 0x2c, 0x32, 0x5d, 0x7d]
+    );
+
+    assert_write!(jsonb_raw_value, write_jsonb,
+        RawValue::from_string(r#"{"a": [1, 2]}"#.to_string()).unwrap(),
+        vec![0x00, 0x00, 0x00, 0x0e, 0x01, 0x7b, 0x22, 0x61, 0x22, 0x3a, 0x20, 0x5b, 0x31, 0x2c, 0x20, 0x32, 0x5d, 0x7d]
+    );
+
+    assert_write!(jsonb_serialize, write_jsonb,
+        PgJson(vec![("a", 1)].into_iter().collect::<BTreeMap<_, _>>()),
+        vec![0x00, 0x00, 0x00, 0x08, 0x01, 0x7b, 0x22, 0x61, 0x22, 0x3a, 0x31, 0x7d]
+    );
+
+    /// Serializes to a longer value each time.
+    struct Growing(Cell<usize>);
+
+    impl Serialize for Growing {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            self.0.set(self.0.get() * 10);
+            serializer.serialize_u64(self.0.get() as u64)
+        }
+    }
+
+    #[test]
+    fn non_deterministic() {
+        let mut encoder = crate::Encoder::new(vec![]);
+
+        assert!(encoder.write_json(PgJson(Growing(Cell::new(1)))).is_err());
+    }
+}
@@ -2,6 +2,10 @@ assert_write!(macaddr, write_macaddr,
     [0xde, 0xaa, 0xad, 0xbe, 0xee, 0xef],
     vec![0x00, 0x00, 0x00, 0x06, 0xde, 0xaa, 0xad, 0xbe, 0xee, 0xef]);
 
+assert_write!(null_macaddr, write_macaddr,
+    None::<[u8; 6]>,
+    vec![0xff, 0xff, 0xff, 0xff]);
+
 
 #[cfg(feature = "with-eui48")]
 mod with_eui48 {
@@ -45,23 +45,44 @@ macro_rules! assert_bool {
         assert_write!($test_name, write_bool, $value, $expected);
     };
 }
-//macro_rules! assert_numeric {
-//    ($test_name:ident, $value:expr, $expected:expr) => {
-//        assert_write!($test_name, write_numeric, $value, $expected);
-//    };
-//}
+macro_rules! assert_numeric {
+    ($test_name:ident, $value:expr, $expected:expr) => {
+        assert_write!($test_name, write_numeric, $value, $expected);
+    };
+}
 
-// TODO:
-// assert_write!(null, write_null(), vec![0xff, 0xff, 0xff, 0xff]);
+#[test]
+fn null() {
+    let mut encoder = crate::Encoder::new(vec![]);
+
+    assert!(encoder.write_null().is_ok());
+    assert_eq!(&vec![0xff, 0xff, 0xff, 0xff], encoder.get_ref());
+}
 
+// Float fixtures use `3.14`, which is not meant to be an approximation of PI
+#[allow(clippy::approx_constant)]
 mod numeric;
+mod money;
 mod boolean;
 mod bytes;
 mod text;
 mod timestamp;
 mod date;
 mod time;
+mod interval;
+mod enumeration;
 mod uuid;
+mod geometric;
+mod geometry;
+mod inet;
 mod macaddr;
 mod macaddr8;
+mod bit;
+mod text_search;
+mod json;
+mod xml;
+mod array;
+mod composite;
+mod range;
+mod multirange;
 
@@ -0,0 +1,34 @@
+use crate::types::PgMoney;
+
+assert_write!(money, write_money,
+    PgMoney(-1234),
+    vec![0x00, 0x00, 0x00, 0x08, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfb, 0x2e]
+);
+
+#[cfg(feature = "with-rust_decimal")]
+mod with_rust_decimal {
+    use rust_decimal::Decimal;
+
+    use crate::types::PgMoney;
+
+    assert_write!(decimal, write_money,
+        PgMoney::from_decimal(Decimal::new(15, 1), 2).unwrap(),
+        vec![0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x96]
+    );
+
+    #[test]
+    fn decimal_trailing_zeroes() {
+        assert_eq!(PgMoney::from_decimal(Decimal::new(-123_400, 4), 2).unwrap(), PgMoney(-1234));
+    }
+
+    #[test]
+    fn decimal_excess_fractional_digits() {
+        assert!(PgMoney::from_decimal(Decimal::new(12_345, 3), 2).is_err());
+        assert_eq!(PgMoney::from_decimal(Decimal::new(12_345, 3), 3).unwrap(), PgMoney(12_345));
+    }
+
+    #[test]
+    fn decimal_overflow() {
+        assert!(PgMoney::from_decimal(Decimal::from(i64::MAX), 2).is_err());
+    }
+}
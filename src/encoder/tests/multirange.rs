@@ -0,0 +1,89 @@
+use std::ops::{Bound, Range};
+
+use crate::types::{PgRange, PgMultirange};
+
+assert_write!(multirange, write_multirange,
+    vec![1..3, 5..8],
+    vec![0x00, 0x00, 0x00, 0x2e, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x11, 0x02, 0x00, 0x00, 0x00,
+         0x04, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00,
+         0x11, 0x02, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00,
+         0x00, 0x08]
+);
+
+assert_write!(empty_multirange, write_multirange,
+    Vec::<Range<i32>>::new(),
+    vec![0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00]
+);
+
+assert_write!(collected_multirange, write_multirange,
+    vec![5..8, 1..3].into_iter().collect::<PgMultirange<i32>>().normalized(),
+    vec![0x00, 0x00, 0x00, 0x2e, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x11, 0x02, 0x00, 0x00, 0x00,
+         0x04, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00,
+         0x11, 0x02, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00,
+         0x00, 0x08]
+);
+
+#[test]
+fn normalized() {
+    use std::ops::Bound::{Included, Excluded};
+
+    let multirange = vec![
+        PgRange::new(Excluded(15), Excluded(16)),
+        PgRange::new(Included(1), Excluded(3)),
+        PgRange::new(Excluded(7), Excluded(9)),
+        PgRange::Empty,
+        PgRange::new(Included(3), Excluded(5)),
+        PgRange::new(Excluded(11), Included(12)),
+        PgRange::new(Included(8), Included(10)),
+        PgRange::new(Excluded(12), Excluded(13)),
+        PgRange::new(Excluded(14), Excluded(15)),
+        PgRange::new(Excluded(20), Excluded(20)),
+    ].into_iter().collect::<PgMultirange<i32>>();
+
+    let expected = vec![
+        PgRange::new(Included(1), Excluded(5)),
+        PgRange::new(Excluded(7), Included(10)),
+        PgRange::new(Excluded(11), Excluded(13)),
+        PgRange::new(Excluded(14), Excluded(15)),
+        PgRange::new(Excluded(15), Excluded(16)),
+    ];
+
+    assert_eq!(expected, multirange.normalized().ranges());
+}
+
+#[test]
+fn normalized_unbounded() {
+    let mut multirange = PgMultirange::new();
+    multirange.push(10..20);
+    multirange.push(..5);
+    multirange.push(15..);
+    multirange.push(..=-1);
+
+    let expected = vec![
+        PgRange::new(Bound::Unbounded, Bound::Excluded(5)),
+        PgRange::new(Bound::Included(10), Bound::Unbounded),
+    ];
+
+    assert_eq!(expected, multirange.normalized().ranges());
+}
+
+#[test]
+fn normalized_null_bounds() {
+    let mut multirange = PgMultirange::new();
+    multirange.push(PgRange::new(Bound::Included(Some(10)), Bound::Excluded(Some(20))));
+    multirange.push(PgRange::new(Bound::Included(Some(1)), Bound::Excluded(None)));
+    multirange.push(PgRange::new(Bound::Excluded(None), Bound::Included(Some(-5))));
+
+    let expected = vec![
+        PgRange::new(Bound::Unbounded, Bound::Included(Some(-5))),
+        PgRange::new(Bound::Included(Some(1)), Bound::Unbounded),
+    ];
+
+    assert_eq!(expected, multirange.normalized().ranges());
+}
+
+assert_write!(normalized_null_bounds_multirange, write_multirange,
+    PgMultirange::from(vec![PgRange::new(Bound::Included(Some(1)), Bound::Excluded(None))]).normalized(),
+    vec![0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x09, 0x12, 0x00, 0x00, 0x00,
+         0x04, 0x00, 0x00, 0x00, 0x01]
+);
@@ -1,5 +1,9 @@
 assert_i16!(negative_i16, -42, vec![0x00, 0x00, 0x00, 0x02, 0xff, 0xd6]);
 
+assert_i16!(some_i16, Some(-42), vec![0x00, 0x00, 0x00, 0x02, 0xff, 0xd6]);
+
+assert_i16!(none_i16, None, vec![0xff, 0xff, 0xff, 0xff]);
+
 assert_i32!(negative_i32, -601, vec![0x00, 0x00, 0x00, 0x04, 0xff, 0xff, 0xfd, 0xa7]);
 
 assert_i64!(positive_64, 320_320, vec![0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0xe3, 0x40]);
@@ -7,3 +11,76 @@ assert_i64!(positive_64, 320_320, vec![0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00,
 assert_f32!(positive_f32, 3.14, vec![0x00, 0x00, 0x00, 0x04, 0x40, 0x48, 0xf5, 0xc3]);
 
 assert_f64!(negative_f64, 3.14, vec![0x00, 0x00, 0x00, 0x08, 0x40, 0x09, 0x1e, 0xb8, 0x51, 0xeb, 0x85, 0x1f]);
+
+assert_f64!(none_f64, None, vec![0xff, 0xff, 0xff, 0xff]);
+
+assert_numeric!(numeric_str, "12345.678",
+    vec![0x00, 0x00, 0x00, 0x0e, 0x00, 0x03, 0x00, 0x01, 0x00, 0x00, 0x00, 0x03, 0x00, 0x01, 0x09, 0x29, 0x1a, 0x7c]);
+
+assert_numeric!(numeric_none, None::<&str>, vec![0xff, 0xff, 0xff, 0xff]);
+
+assert_numeric!(numeric_negative_fraction, "-0.0042",
+    vec![0x00, 0x00, 0x00, 0x0a, 0x00, 0x01, 0xff, 0xff, 0x40, 0x00, 0x00, 0x04, 0x00, 0x2a]);
+
+assert_numeric!(numeric_exponent, "1.5e5",
+    vec![0x00, 0x00, 0x00, 0x0a, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0f]);
+
+assert_numeric!(numeric_zero, "0.00".to_string(),
+    vec![0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02]);
+
+assert_numeric!(numeric_integer, 320_320i64,
+    vec![0x00, 0x00, 0x00, 0x0c, 0x00, 0x02, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x01, 0x40]);
+
+assert_numeric!(numeric_nan, f64::NAN,
+    vec![0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x00, 0xc0, 0x00, 0x00, 0x00]);
+
+assert_numeric!(numeric_negative_infinity, "-Infinity",
+    vec![0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x00, 0xf0, 0x00, 0x00, 0x00]);
+
+#[test]
+fn numeric_invalid_str() {
+    let mut encoder = crate::Encoder::new(vec![]);
+
+    assert!(encoder.write_numeric("12.3.4").is_err());
+    assert!(encoder.get_ref().is_empty());
+}
+
+// Expected values are produced by the PostgreSQL `numeric_send` function.
+
+#[cfg(feature = "with-rust_decimal")]
+mod with_rust_decimal {
+    use std::str::FromStr;
+
+    use rust_decimal::Decimal;
+
+    assert_numeric!(decimal_scale, Decimal::from_str("123.4500").unwrap(),
+        vec![0x00, 0x00, 0x00, 0x0c, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x00, 0x7b, 0x11, 0x94]
+    );
+
+    assert_numeric!(decimal_min, Decimal::MIN,
+        vec![0x00, 0x00, 0x00, 0x18, 0x00, 0x08, 0x00, 0x07, 0x40, 0x00, 0x00, 0x00, 0x00, 0x07, 0x24, 0x0c, 0x06, 0x59, 0x05, 0x92, 0x10, 0xf1, 0x17, 0x2f, 0x11, 0x2b, 0x01, 0x4f]
+    );
+
+    assert_numeric!(decimal_zero, Decimal::from_str("0.000").unwrap(),
+        vec![0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03]
+    );
+}
+
+#[cfg(feature = "with-bigdecimal")]
+mod with_bigdecimal {
+    use std::str::FromStr;
+
+    use bigdecimal::BigDecimal;
+
+    assert_numeric!(big_decimal_exponent, BigDecimal::from_str("1.23E+1000").unwrap(),
+        vec![0x00, 0x00, 0x00, 0x0c, 0x00, 0x02, 0x00, 0xfa, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x08, 0xfc]
+    );
+
+    assert_numeric!(big_decimal_fraction, BigDecimal::from_str("-0.00000000000000000000000001").unwrap(),
+        vec![0x00, 0x00, 0x00, 0x0a, 0x00, 0x01, 0xff, 0xf9, 0x40, 0x00, 0x00, 0x1a, 0x00, 0x64]
+    );
+
+    assert_numeric!(big_decimal_scale, BigDecimal::from_str("123.4500").unwrap(),
+        vec![0x00, 0x00, 0x00, 0x0c, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x00, 0x7b, 0x11, 0x94]
+    );
+}
@@ -0,0 +1,65 @@
+use std::ops::Bound;
+
+use crate::types::PgRange;
+
+assert_write!(range, write_range,
+    1..10,
+    vec![0x00, 0x00, 0x00, 0x11, 0x02, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+         0x04, 0x00, 0x00, 0x00, 0x0a]
+);
+
+assert_write!(range_inclusive, write_range,
+    1..=10,
+    vec![0x00, 0x00, 0x00, 0x11, 0x06, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+         0x04, 0x00, 0x00, 0x00, 0x0a]
+);
+
+assert_write!(range_from, write_range,
+    5i64..,
+    vec![0x00, 0x00, 0x00, 0x0d, 0x12, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+         0x05]
+);
+
+assert_write!(range_to, write_range,
+    ..10,
+    vec![0x00, 0x00, 0x00, 0x09, 0x08, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x0a]
+);
+
+assert_write!(empty_range, write_range,
+    PgRange::<i32>::Empty,
+    vec![0x00, 0x00, 0x00, 0x01, 0x01]
+);
+
+assert_write!(null_bound, write_range,
+    PgRange::new(Bound::Excluded(Some(1)), Bound::Included(None)),
+    vec![0x00, 0x00, 0x00, 0x09, 0x10, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x01]
+);
+
+#[cfg(feature = "with-chrono")]
+mod with_chrono {
+    use std::str::FromStr;
+
+    use chrono::NaiveDate;
+
+    assert_write!(date_range, write_range,
+        ..NaiveDate::from_str("2019-01-27").unwrap(),
+        vec![0x00, 0x00, 0x00, 0x09, 0x08, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x1b, 0x36]
+    );
+}
+
+#[cfg(feature = "with-rust_decimal")]
+mod with_rust_decimal {
+    use std::ops::Bound;
+    use std::str::FromStr;
+
+    use rust_decimal::Decimal;
+
+    use crate::types::PgRange;
+
+    assert_write!(numeric_range, write_range,
+        PgRange::new(Bound::Excluded(Decimal::from_str("1.5").unwrap()), Bound::Included(Decimal::from_str("2.5").unwrap())),
+        vec![0x00, 0x00, 0x00, 0x21, 0x04, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00,
+             0x01, 0x00, 0x01, 0x13, 0x88, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00,
+             0x01, 0x00, 0x02, 0x13, 0x88]
+    );
+}
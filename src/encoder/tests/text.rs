@@ -5,3 +5,19 @@ assert_write!(static_str, write_str,
 assert_write!(heap_string, write_str,
     "hello world".to_string(),
     vec![0x00, 0x00, 0x00, 0x0b, 0x68, 0x65, 0x6c, 0x6c, 0x6f, 0x20, 0x77, 0x6f, 0x72, 0x6c, 0x64]);
+
+assert_write!(optional_str, write_str,
+    Some("hello world"),
+    vec![0x00, 0x00, 0x00, 0x0b, 0x68, 0x65, 0x6c, 0x6c, 0x6f, 0x20, 0x77, 0x6f, 0x72, 0x6c, 0x64]);
+
+assert_write!(null_string, write_str,
+    None::<String>,
+    vec![0xff, 0xff, 0xff, 0xff]);
+
+assert_write!(shared_str, write_str,
+    std::rc::Rc::<str>::from("hello world"),
+    vec![0x00, 0x00, 0x00, 0x0b, 0x68, 0x65, 0x6c, 0x6c, 0x6f, 0x20, 0x77, 0x6f, 0x72, 0x6c, 0x64]);
+
+assert_write!(atomic_shared_str, write_str,
+    std::sync::Arc::<str>::from("hello world"),
+    vec![0x00, 0x00, 0x00, 0x0b, 0x68, 0x65, 0x6c, 0x6c, 0x6f, 0x20, 0x77, 0x6f, 0x72, 0x6c, 0x64]);
@@ -0,0 +1,67 @@
+use crate::types::{PgLexeme, PgTsQuery, PgTsVector, PgTsWeight};
+
+assert_write!(tsvector, write_tsvector,
+    PgTsVector::new(vec![
+        PgLexeme::new("cat").position(3, PgTsWeight::A).position(5, PgTsWeight::D),
+        PgLexeme::new("fat").position(1, PgTsWeight::B),
+        PgLexeme::new("rat"),
+    ]),
+    vec![0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x03, 0x63, 0x61, 0x74, 0x00, 0x00, 0x02, 0xc0, 0x03,
+         0x00, 0x05, 0x66, 0x61, 0x74, 0x00, 0x00, 0x01, 0x80, 0x01, 0x72, 0x61, 0x74, 0x00, 0x00, 0x00]
+);
+
+assert_write!(tsvector_empty, write_tsvector,
+    PgTsVector::default(),
+    vec![0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00]
+);
+
+#[test]
+fn tsvector_misordered_positions() {
+    let vector = PgTsVector::new(vec![PgLexeme::new("cat").position(5, PgTsWeight::A).position(3, PgTsWeight::A)]);
+    let mut encoder = crate::Encoder::new(vec![]);
+
+    assert!(encoder.write_tsvector(vector).is_err());
+    assert!(encoder.get_ref().is_empty());
+}
+
+#[test]
+fn tsvector_empty_lexeme() {
+    let mut encoder = crate::Encoder::new(vec![]);
+
+    assert!(encoder.write_tsvector(PgTsVector::new(vec![PgLexeme::new("")])).is_err());
+    assert!(encoder.get_ref().is_empty());
+}
+
+// 'fat' & ( 'rat' | !'cat':*AB )
+assert_write!(tsquery, write_tsquery,
+    PgTsQuery::and(
+        PgTsQuery::lexeme("fat"),
+        PgTsQuery::or(
+            PgTsQuery::lexeme("rat"),
+            PgTsQuery::not(PgTsQuery::Lexeme {
+                word: "cat".to_string(),
+                weights: vec![PgTsWeight::A, PgTsWeight::B],
+                prefix: true,
+            }),
+        ),
+    ),
+    vec![0x00, 0x00, 0x00, 0x1f, 0x00, 0x00, 0x00, 0x06, 0x02, 0x02, 0x02, 0x03, 0x02, 0x01, 0x01, 0x0c,
+         0x01, 0x63, 0x61, 0x74, 0x00, 0x01, 0x00, 0x00, 0x72, 0x61, 0x74, 0x00, 0x01, 0x00, 0x00, 0x66,
+         0x61, 0x74, 0x00]
+);
+
+// 'fat' <2> 'rat'
+assert_write!(tsquery_phrase, write_tsquery,
+    &PgTsQuery::phrase(PgTsQuery::lexeme("fat"), PgTsQuery::lexeme("rat"), 2),
+    vec![0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x03, 0x02, 0x04, 0x00, 0x02, 0x01, 0x00, 0x00, 0x72,
+         0x61, 0x74, 0x00, 0x01, 0x00, 0x00, 0x66, 0x61, 0x74, 0x00]
+);
+
+#[test]
+fn tsquery_phrase_distance() {
+    let query = PgTsQuery::phrase(PgTsQuery::lexeme("fat"), PgTsQuery::lexeme("rat"), 16384);
+    let mut encoder = crate::Encoder::new(vec![]);
+
+    assert!(encoder.write_tsquery(query).is_err());
+    assert!(encoder.get_ref().is_empty());
+}
@@ -1,11 +1,82 @@
+use crate::types::{PgTimeTz, OutOfRange};
+
+// 13:28:01.789+03, offset is written as seconds to the west of UTC
+assert_write!(pg_timetz_east, write_time_with_time_zone,
+    PgTimeTz::new(48_481_789_000, 3 * 60 * 60),
+    vec![0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0b, 0x49, 0xbd, 0x64, 0x48, 0xff, 0xff, 0xd5, 0xd0]
+);
+
+// 13:28:01.789-05:30
+assert_write!(pg_timetz_west, write_time_with_time_zone,
+    PgTimeTz::new(48_481_789_000, -(5 * 60 + 30) * 60),
+    vec![0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0b, 0x49, 0xbd, 0x64, 0x48, 0x00, 0x00, 0x4d, 0x58]
+);
+
+#[test]
+fn pg_timetz_out_of_range() {
+    let mut encoder = crate::Encoder::new(vec![]);
+
+    for &value in &[PgTimeTz::new(86_400_000_001, 0), PgTimeTz::new(-1, 0), PgTimeTz::new(0, 16 * 60 * 60)] {
+        let err = encoder.write_time_with_time_zone(value).unwrap_err();
+        assert_eq!(err.get_ref().and_then(|inner| inner.downcast_ref()), Some(&OutOfRange::new("time with time zone")));
+    }
+    assert!(encoder.get_ref().is_empty());
+}
+
 #[cfg(feature = "with-chrono")]
 mod with_chrono {
     use std::str::FromStr;
 
-    use chrono::NaiveTime;
+    use chrono::{FixedOffset, NaiveTime};
 
     assert_write!(naive_time, write_time,
         NaiveTime::from_str("13:28:01.789").unwrap(),
         vec![0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x0b, 0x49, 0xbd, 0x64, 0x48]
     );
+
+    assert_write!(naive_time_fixed_offset_east, write_time_with_time_zone,
+        (NaiveTime::from_str("13:28:01.789").unwrap(), FixedOffset::east_opt(3 * 60 * 60).unwrap()),
+        vec![0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0b, 0x49, 0xbd, 0x64, 0x48, 0xff, 0xff, 0xd5, 0xd0]
+    );
+
+    assert_write!(naive_time_fixed_offset_west, write_time_with_time_zone,
+        (NaiveTime::from_str("13:28:01.789").unwrap(), FixedOffset::west_opt((5 * 60 + 30) * 60).unwrap()),
+        vec![0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0b, 0x49, 0xbd, 0x64, 0x48, 0x00, 0x00, 0x4d, 0x58]
+    );
+}
+
+#[cfg(feature = "with-time")]
+mod with_time {
+    use time::{Time, UtcOffset};
+
+    assert_write!(time, write_time,
+        Time::from_hms_milli(13, 28, 1, 789).unwrap(),
+        vec![0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x0b, 0x49, 0xbd, 0x64, 0x48]
+    );
+
+    assert_write!(time_utc_offset_east, write_time_with_time_zone,
+        (Time::from_hms_milli(13, 28, 1, 789).unwrap(), UtcOffset::from_hms(3, 0, 0).unwrap()),
+        vec![0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0b, 0x49, 0xbd, 0x64, 0x48, 0xff, 0xff, 0xd5, 0xd0]
+    );
+
+    assert_write!(time_utc_offset_west, write_time_with_time_zone,
+        (Time::from_hms_milli(13, 28, 1, 789).unwrap(), UtcOffset::from_hms(-5, -30, 0).unwrap()),
+        vec![0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0b, 0x49, 0xbd, 0x64, 0x48, 0x00, 0x00, 0x4d, 0x58]
+    );
+}
+
+#[cfg(feature = "with-jiff")]
+mod with_jiff {
+    use jiff::civil::Time;
+    use jiff::tz::Offset;
+
+    assert_write!(civil_time, write_time,
+        Time::constant(13, 28, 1, 789_000_000),
+        vec![0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x0b, 0x49, 0xbd, 0x64, 0x48]
+    );
+
+    assert_write!(civil_time_offset_east, write_time_with_time_zone,
+        (Time::constant(13, 28, 1, 789_000_000), Offset::constant(3)),
+        vec![0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0b, 0x49, 0xbd, 0x64, 0x48, 0xff, 0xff, 0xd5, 0xd0]
+    );
 }
@@ -1,8 +1,66 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::types::PgTimestamp;
+
+assert_write!(system_time, write_timestamp_with_time_zone,
+    UNIX_EPOCH + Duration::from_secs(1_548_584_880),
+    vec![0x00, 0x00, 0x00, 0x08, 0x00, 0x02, 0x23, 0x6c, 0xc8, 0x75, 0x6c, 0x00]
+);
+
+// 1999-12-31T23:59:59.999999, after the Unix epoch, but before the PostgreSQL one
+assert_write!(system_time_before_postgres_epoch, write_timestamp_with_time_zone,
+    UNIX_EPOCH + Duration::from_nanos(946_684_799_999_999_999),
+    vec![0x00, 0x00, 0x00, 0x08, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff]
+);
+
+// 1969-12-31T23:59:59
+assert_write!(system_time_before_unix_epoch, write_timestamp_with_time_zone,
+    UNIX_EPOCH - Duration::from_secs(1),
+    vec![0x00, 0x00, 0x00, 0x08, 0xff, 0xfc, 0xa2, 0xfe, 0xc4, 0xb8, 0xdd, 0xc0]
+);
+
+// 1969-12-31T23:59:59.999999999, sub-microsecond part is rounded down
+assert_write!(system_time_before_unix_epoch_rounding, write_timestamp_with_time_zone,
+    UNIX_EPOCH - Duration::from_nanos(1),
+    vec![0x00, 0x00, 0x00, 0x08, 0xff, 0xfc, 0xa2, 0xfe, 0xc4, 0xc8, 0x1f, 0xff]
+);
+
+assert_write!(some_system_time, write_timestamp_with_time_zone,
+    Some(UNIX_EPOCH + Duration::from_secs(1_548_584_880)),
+    vec![0x00, 0x00, 0x00, 0x08, 0x00, 0x02, 0x23, 0x6c, 0xc8, 0x75, 0x6c, 0x00]
+);
+
+assert_write!(null_system_time, write_timestamp_with_time_zone,
+    None::<SystemTime>,
+    vec![0xff, 0xff, 0xff, 0xff]
+);
+
+assert_write!(infinity, write_timestamp_with_time_zone,
+    PgTimestamp::<SystemTime>::Infinity,
+    vec![0x00, 0x00, 0x00, 0x08, 0x7f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff]
+);
+
+assert_write!(neg_infinity, write_timestamp_with_time_zone,
+    PgTimestamp::<SystemTime>::NegInfinity,
+    vec![0x00, 0x00, 0x00, 0x08, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]
+);
+
+assert_write!(finite, write_timestamp_with_time_zone,
+    PgTimestamp::or_infinity(Some(UNIX_EPOCH + Duration::from_secs(1_548_584_880))),
+    vec![0x00, 0x00, 0x00, 0x08, 0x00, 0x02, 0x23, 0x6c, 0xc8, 0x75, 0x6c, 0x00]
+);
+
 #[cfg(feature = "with-chrono")]
 mod with_chrono {
     use std::str::FromStr;
 
-    use chrono::{NaiveDateTime, DateTime, Local};
+    use chrono::{NaiveDate, NaiveDateTime, DateTime, Duration, Local};
+
+    use crate::types::{PgTimestamp, OutOfRange};
+
+    fn min_timestamp() -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(-4713, 11, 24).unwrap().and_hms_opt(0, 0, 0).unwrap()
+    }
 
     assert_write!(naive_date_time, write_timestamp,
         NaiveDateTime::from_str("2019-01-27T13:28:00").unwrap(),
@@ -13,4 +71,113 @@ mod with_chrono {
         DateTime::<Local>::from_str("2019-01-27T13:28:00+03:00").unwrap(),
         vec![0x00, 0x00, 0x00, 0x08, 0x00, 0x02, 0x23, 0x6c, 0xc8, 0x75, 0x6c, 0x00]
     );
+
+    assert_write!(min_naive_date_time, write_timestamp,
+        min_timestamp(),
+        vec![0x00, 0x00, 0x00, 0x08, 0xfd, 0x0f, 0x7c, 0xc1, 0x41, 0x1f, 0xa0, 0x00]
+    );
+
+    // Out of the nanoseconds range, which ends in 2262
+    assert_write!(far_future_naive_date_time, write_timestamp,
+        NaiveDate::from_ymd_opt(200_000, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap(),
+        vec![0x00, 0x00, 0x00, 0x08, 0x56, 0xb6, 0x56, 0x5e, 0x4a, 0x80, 0xa0, 0x00]
+    );
+
+    #[test]
+    fn timestamp_out_of_range() {
+        let mut encoder = crate::Encoder::new(vec![]);
+        let err = encoder.write_timestamp(min_timestamp() - Duration::microseconds(1)).unwrap_err();
+
+        assert_eq!(err.get_ref().and_then(|inner| inner.downcast_ref()), Some(&OutOfRange::new("timestamp")));
+        assert!(encoder.get_ref().is_empty());
+    }
+
+    assert_write!(naive_date_time_or_infinity, write_timestamp,
+        PgTimestamp::<NaiveDateTime>::or_infinity(None),
+        vec![0x00, 0x00, 0x00, 0x08, 0x7f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff]
+    );
+
+    assert_write!(naive_date_time_or_neg_infinity, write_timestamp,
+        PgTimestamp::<NaiveDateTime>::or_neg_infinity(None),
+        vec![0x00, 0x00, 0x00, 0x08, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]
+    );
+}
+
+#[cfg(feature = "with-time")]
+mod with_time {
+    use time::{Date, Month, PrimitiveDateTime, Time, UtcOffset};
+
+    use crate::types::OutOfRange;
+
+    fn date_time() -> PrimitiveDateTime {
+        let date = Date::from_calendar_date(2019, Month::January, 27).unwrap();
+        PrimitiveDateTime::new(date, Time::from_hms(13, 28, 0).unwrap())
+    }
+
+    assert_write!(primitive_date_time, write_timestamp,
+        date_time(),
+        vec![0x00, 0x00, 0x00, 0x08, 0x00, 0x02, 0x23, 0x6f, 0x4c, 0x30, 0x58, 0x00]
+    );
+
+    assert_write!(offset_date_time, write_timestamp_with_time_zone,
+        date_time().assume_offset(UtcOffset::from_hms(3, 0, 0).unwrap()),
+        vec![0x00, 0x00, 0x00, 0x08, 0x00, 0x02, 0x23, 0x6c, 0xc8, 0x75, 0x6c, 0x00]
+    );
+
+    // 1999-12-31T23:59:59.999999
+    assert_write!(primitive_date_time_before_epoch, write_timestamp,
+        PrimitiveDateTime::new(
+            Date::from_calendar_date(1999, Month::December, 31).unwrap(),
+            Time::from_hms_micro(23, 59, 59, 999_999).unwrap(),
+        ),
+        vec![0x00, 0x00, 0x00, 0x08, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff]
+    );
+
+    #[test]
+    fn overflowing_date_time() {
+        let date = Date::from_calendar_date(500_000, Month::January, 1).unwrap();
+        let mut encoder = crate::Encoder::new(vec![]);
+
+        let err = encoder.write_timestamp(PrimitiveDateTime::new(date, Time::MIDNIGHT)).unwrap_err();
+        assert_eq!(err.get_ref().and_then(|inner| inner.downcast_ref()), Some(&OutOfRange::new("timestamp")));
+        assert!(encoder.get_ref().is_empty());
+    }
+}
+
+#[cfg(feature = "with-jiff")]
+mod with_jiff {
+    use jiff::Timestamp;
+    use jiff::civil::DateTime;
+    use jiff::tz::{Offset, TimeZone};
+
+    assert_write!(civil_date_time, write_timestamp,
+        DateTime::constant(2019, 1, 27, 13, 28, 0, 0),
+        vec![0x00, 0x00, 0x00, 0x08, 0x00, 0x02, 0x23, 0x6f, 0x4c, 0x30, 0x58, 0x00]
+    );
+
+    assert_write!(timestamp, write_timestamp_with_time_zone,
+        "2019-01-27T10:28:00Z".parse::<Timestamp>().unwrap(),
+        vec![0x00, 0x00, 0x00, 0x08, 0x00, 0x02, 0x23, 0x6c, 0xc8, 0x75, 0x6c, 0x00]
+    );
+
+    // Zoned datetime is written in UTC
+    assert_write!(zoned, write_timestamp_with_time_zone,
+        DateTime::constant(2019, 1, 27, 13, 28, 0, 0)
+            .to_zoned(TimeZone::fixed(Offset::constant(3)))
+            .unwrap(),
+        vec![0x00, 0x00, 0x00, 0x08, 0x00, 0x02, 0x23, 0x6c, 0xc8, 0x75, 0x6c, 0x00]
+    );
+
+    // 1999-12-31T23:59:59.999999999, sub-microsecond part is truncated
+    assert_write!(civil_date_time_before_epoch, write_timestamp,
+        DateTime::constant(1999, 12, 31, 23, 59, 59, 999_999_999),
+        vec![0x00, 0x00, 0x00, 0x08, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff]
+    );
+
+    assert_write!(zoned_ref, write_timestamp_with_time_zone,
+        &DateTime::constant(2019, 1, 27, 13, 28, 0, 0)
+            .to_zoned(TimeZone::fixed(Offset::constant(3)))
+            .unwrap(),
+        vec![0x00, 0x00, 0x00, 0x08, 0x00, 0x02, 0x23, 0x6c, 0xc8, 0x75, 0x6c, 0x00]
+    );
 }
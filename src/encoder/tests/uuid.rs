@@ -15,4 +15,14 @@ mod with_uuid {
         Uuid::from_str("1d662762-2010-11e9-ad8b-c869cdb5cd46").unwrap(),
         vec![0x00, 0x00, 0x00, 0x10, 0x1d, 0x66, 0x27, 0x62, 0x20, 0x10, 0x11, 0xe9, 0xad, 0x8b, 0xc8, 0x69, 0xcd, 0xb5, 0xcd, 0x46]
     );
+
+    assert_write!(optional_uuid, write_uuid,
+        Uuid::from_str("1d662762-2010-11e9-ad8b-c869cdb5cd46").ok(),
+        vec![0x00, 0x00, 0x00, 0x10, 0x1d, 0x66, 0x27, 0x62, 0x20, 0x10, 0x11, 0xe9, 0xad, 0x8b, 0xc8, 0x69, 0xcd, 0xb5, 0xcd, 0x46]
+    );
+
+    assert_write!(null_uuid, write_uuid,
+        None::<Uuid>,
+        vec![0xff, 0xff, 0xff, 0xff]
+    );
 }
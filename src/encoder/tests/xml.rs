@@ -0,0 +1,45 @@
+assert_write!(str, write_xml,
+    "<a>b</a>",
+    vec![0x00, 0x00, 0x00, 0x08, 0x3c, 0x61, 0x3e, 0x62, 0x3c, 0x2f, 0x61, 0x3e]
+);
+
+assert_write!(string, write_xml,
+    String::from("<a/>"),
+    vec![0x00, 0x00, 0x00, 0x04, 0x3c, 0x61, 0x2f, 0x3e]
+);
+
+#[cfg(feature = "with-quick-xml")]
+mod with_quick_xml {
+    use crate::types::PgCheckedXml;
+
+    assert_write!(checked, write_xml,
+        PgCheckedXml("<a x=\"&amp;\">b</a>"),
+        vec![0x00, 0x00, 0x00, 0x12, 0x3c, 0x61, 0x20, 0x78, 0x3d, 0x22, 0x26, 0x61, 0x6d, 0x70, 0x3b, 0x22,
+             0x3e, 0x62, 0x3c, 0x2f, 0x61, 0x3e]
+    );
+
+    // Content with multiple root elements and top-level text is allowed
+    assert_write!(checked_content, write_xml,
+        PgCheckedXml(String::from("a<b/><c/>")),
+        vec![0x00, 0x00, 0x00, 0x09, 0x61, 0x3c, 0x62, 0x2f, 0x3e, 0x3c, 0x63, 0x2f, 0x3e]
+    );
+
+    #[test]
+    fn malformed() {
+        let documents = [
+            "<a>b</c>",
+            "<a><b></a>",
+            "<a>",
+            "</a>",
+            "<a>&unknown;</a>",
+            "<a>fish & chips</a>",
+            "<a x=\"1\" x=\"2\"/>",
+        ];
+        for document in &documents {
+            let mut encoder = crate::Encoder::new(vec![]);
+
+            assert!(encoder.write_xml(PgCheckedXml(document)).is_err(), "{} should be rejected", document);
+            assert!(encoder.get_ref().is_empty());
+        }
+    }
+}
@@ -5,3 +5,5 @@ mod encoder;
 pub mod types;
 
 pub use encoder::Encoder;
+#[cfg(feature = "derive")]
+pub use pgcopy_derive::PgEnum;
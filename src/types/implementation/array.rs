@@ -0,0 +1,165 @@
+use std::io;
+
+use byteorder::{WriteBytesExt, NetworkEndian};
+
+use super::write_length_prefixed;
+use crate::types::{Array, Element};
+
+// https://github.com/postgres/postgres/blob/master/src/backend/utils/adt/arrayfuncs.c
+
+/// Maximum dimensions amount supported by the server.
+const MAXDIM: usize = 6;
+
+/// Array dimension.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Dimension {
+    /// Elements amount in this dimension.
+    pub len: i32,
+    /// Index of the first element in this dimension, PostgreSQL arrays are indexed starting from `1` by default.
+    pub lower_bound: i32,
+}
+
+impl Dimension {
+    /// Creates new dimension description.
+    pub fn new(len: i32, lower_bound: i32) -> Dimension {
+        Dimension {
+            len,
+            lower_bound,
+        }
+    }
+}
+
+/// Array with an explicit dimensions layout and element type.
+///
+/// Elements of the multi-dimensional arrays are expected to be laid out in the row-major order,
+/// same as PostgreSQL does it: `{{1, 2, 3}, {4, 5, 6}}` array should be passed as
+/// `[1, 2, 3, 4, 5, 6]` elements with `2` and `3` elements dimensions.
+///
+/// ```edition2018
+/// # use pgcopy::Encoder;
+/// # use pgcopy::types::{PgArray, Dimension};
+/// #
+/// let mut encoder = Encoder::new(vec![]);
+/// let elements = [1i32, 2, 3, 4, 5, 6];
+///
+/// // '[0:1][1:3]={{1,2,3},{4,5,6}}'::int4[]
+/// let array = PgArray::new(&elements)
+///     .with_dimensions(&[Dimension::new(2, 0), Dimension::new(3, 1)]);
+///
+/// encoder.write_array(array).unwrap();
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct PgArray<'a, T> {
+    elements: &'a [T],
+    dimensions: Vec<Dimension>,
+    element_oid: u32,
+}
+
+impl<'a, T: Element> PgArray<'a, T> {
+    /// Creates one-dimensional array, indexed starting from `1`.
+    pub fn new(elements: &'a [T]) -> PgArray<'a, T> {
+        PgArray {
+            elements,
+            dimensions: vec![Dimension::new(elements.len() as i32, 1)],
+            element_oid: T::OID,
+        }
+    }
+
+    /// Replaces array dimensions.
+    ///
+    /// Product of dimensions sizes is required to be equal to the elements amount.
+    pub fn with_dimensions(mut self, dimensions: &[Dimension]) -> PgArray<'a, T> {
+        self.dimensions = dimensions.to_vec();
+        self
+    }
+
+    /// Replaces element type OID.
+    ///
+    /// Required for the user-defined element types, like enums or composite types,
+    /// or for the types sharing the same Rust representation, like `varchar` and `text`.
+    pub fn with_element_oid(mut self, oid: u32) -> PgArray<'a, T> {
+        self.element_oid = oid;
+        self
+    }
+}
+
+fn invalid_input(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, message)
+}
+
+fn write_array<W, T>(writer: &mut W, elements: &[T], dimensions: &[Dimension], element_oid: u32) -> io::Result<()>
+        where W: io::Write, T: Element {
+    if dimensions.len() > MAXDIM {
+        return Err(invalid_input("number of array dimensions exceeds the maximum allowed (6)"));
+    }
+    if dimensions.is_empty() && !elements.is_empty() {
+        return Err(invalid_input("array dimensions do not match the elements amount"));
+    }
+    let mut items = 1usize;
+    for dimension in dimensions {
+        if dimension.len < 0 || dimension.lower_bound.checked_add(dimension.len).is_none() {
+            return Err(invalid_input("array dimension is out of range"));
+        }
+        items = items.saturating_mul(dimension.len as usize);
+    }
+    if items != elements.len() {
+        return Err(invalid_input("array dimensions do not match the elements amount"));
+    }
+
+    // Empty arrays are always represented with zero dimensions
+    let dimensions = if elements.is_empty() { &[] } else { dimensions };
+    let has_nulls = elements.iter().any(Element::is_null);
+
+    write_length_prefixed(writer, |buf| {
+        buf.write_i32::<NetworkEndian>(dimensions.len() as i32)?;
+        buf.write_i32::<NetworkEndian>(has_nulls as i32)?;
+        buf.write_u32::<NetworkEndian>(element_oid)?;
+        for dimension in dimensions {
+            buf.write_i32::<NetworkEndian>(dimension.len)?;
+            buf.write_i32::<NetworkEndian>(dimension.lower_bound)?;
+        }
+        for element in elements {
+            element.to_writer(buf)?;
+        }
+
+        Ok(())
+    })
+}
+
+fn write_slice<W: io::Write, T: Element>(writer: &mut W, elements: &[T]) -> io::Result<()> {
+    if elements.len() > i32::MAX as usize {
+        return Err(invalid_input("array size exceeds the maximum allowed"));
+    }
+
+    write_array(writer, elements, &[Dimension::new(elements.len() as i32, 1)], T::OID)
+}
+
+impl<'a, T: Element> Array for PgArray<'a, T> {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        write_array(writer, self.elements, &self.dimensions, self.element_oid)
+    }
+}
+
+impl<T: Element> Array for &[T] {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        write_slice(writer, self)
+    }
+}
+
+impl<T: Element, const N: usize> Array for [T; N] {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        write_slice(writer, self)
+    }
+}
+
+impl<T: Element> Array for Vec<T> {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        write_slice(writer, self)
+    }
+}
+
+impl<T: Element> Array for &Vec<T> {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        write_slice(writer, self)
+    }
+}
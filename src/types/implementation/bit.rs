@@ -0,0 +1,155 @@
+use std::io;
+use std::iter::FromIterator;
+
+use byteorder::{WriteBytesExt, NetworkEndian};
+
+use crate::types::{oid, Element, Bit, VarBit};
+
+// https://github.com/postgres/postgres/blob/master/src/backend/utils/adt/varbit.c
+
+/// Native `bit` and `bit varying` value, a string of `len` bits packed into `bytes`,
+/// starting from the most significant bit of the first byte.
+///
+/// Padding bits of the last byte are ignored and always written as zeroes.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct PgBitString {
+    pub len: usize,
+    pub bytes: Vec<u8>,
+}
+
+impl PgBitString {
+    /// Creates new bit string from the `len` bits packed into `bytes`.
+    pub fn new(len: usize, bytes: Vec<u8>) -> PgBitString {
+        PgBitString {
+            len,
+            bytes,
+        }
+    }
+}
+
+impl<'a> FromIterator<&'a bool> for PgBitString {
+    fn from_iter<I: IntoIterator<Item = &'a bool>>(iter: I) -> PgBitString {
+        iter.into_iter().copied().collect()
+    }
+}
+
+impl FromIterator<bool> for PgBitString {
+    fn from_iter<I: IntoIterator<Item = bool>>(iter: I) -> PgBitString {
+        let mut bits = PgBitString::default();
+        for bit in iter {
+            if bits.len % 8 == 0 {
+                bits.bytes.push(0);
+            }
+            if bit {
+                bits.bytes[bits.len / 8] |= 0x80 >> (bits.len % 8);
+            }
+            bits.len += 1;
+        }
+
+        bits
+    }
+}
+
+fn invalid_input(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, message)
+}
+
+/// Writes `len` bits from the `bytes`, zeroing the padding bits of the last byte.
+fn write_bits<W: io::Write>(writer: &mut W, len: usize, bytes: &[u8]) -> io::Result<()> {
+    let bytes_len = len.div_ceil(8);
+    if bytes.len() != bytes_len {
+        return Err(invalid_input("bit string length does not match its data length"));
+    }
+    if len > i32::MAX as usize {
+        return Err(invalid_input("bit string length exceeds the maximum allowed"));
+    }
+
+    writer.write_i32::<NetworkEndian>(4 + bytes_len as i32)?;
+    writer.write_i32::<NetworkEndian>(len as i32)?;
+    if let Some((last, rest)) = bytes.split_last() {
+        let padding = bytes_len * 8 - len;
+        writer.write_all(rest)?;
+        writer.write_u8(last & (0xFF << padding))?;
+    }
+
+    Ok(())
+}
+
+fn write_bools<W: io::Write>(writer: &mut W, bits: &[bool]) -> io::Result<()> {
+    let bits: PgBitString = bits.iter().collect();
+
+    write_bits(writer, bits.len, &bits.bytes)
+}
+
+macro_rules! impl_bit_string {
+    ($($tr:ident),*) => {
+        $(
+            impl $tr for PgBitString {
+                fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+                    write_bits(writer, self.len, &self.bytes)
+                }
+            }
+
+            impl $tr for &PgBitString {
+                fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+                    write_bits(writer, self.len, &self.bytes)
+                }
+            }
+
+            impl $tr for &[bool] {
+                fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+                    write_bools(writer, self)
+                }
+            }
+
+            impl<const N: usize> $tr for [bool; N] {
+                fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+                    write_bools(writer, self)
+                }
+            }
+
+            impl $tr for Vec<bool> {
+                fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+                    write_bools(writer, self)
+                }
+            }
+        )*
+    };
+}
+
+impl_bit_string!(Bit, VarBit);
+
+impl Element for PgBitString {
+    const OID: u32 = oid::VARBIT;
+
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        VarBit::to_writer(self, writer)
+    }
+}
+
+#[cfg(feature = "with-bit-vec")]
+mod with_bit_vec {
+    use super::*;
+
+    use bit_vec::BitVec;
+
+    impl Bit for BitVec {
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            write_bits(writer, self.len(), &self.to_bytes())
+        }
+    }
+
+    impl VarBit for BitVec {
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            write_bits(writer, self.len(), &self.to_bytes())
+        }
+    }
+
+    impl Element for BitVec {
+        const OID: u32 = oid::VARBIT;
+
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            VarBit::to_writer(self, writer)
+        }
+    }
+}
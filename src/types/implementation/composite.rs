@@ -0,0 +1,102 @@
+use std::io;
+
+use byteorder::{WriteBytesExt, NetworkEndian};
+
+use crate::types::{oid, Composite, Element};
+
+// https://github.com/postgres/postgres/blob/master/src/backend/utils/adt/rowtypes.c
+
+/// Composite type value, built field by field.
+///
+/// Fields should be appended in the same order as they are declared in the composite type,
+/// each field type OID is checked by server to match the declared one.
+///
+/// ```edition2018
+/// # use std::io;
+/// # use pgcopy::Encoder;
+/// # use pgcopy::types::{PgComposite, oid};
+/// #
+/// # fn main() -> io::Result<()> {
+/// // CREATE TYPE inventory_item AS (name text, supplier_id integer, price numeric);
+/// let mut item = PgComposite::new();
+/// item.field("fuzzy dice")?
+///     .field(42i32)?
+///     .field_with_oid(oid::NUMERIC, None::<i32>)?;
+///
+/// let mut encoder = Encoder::new(vec![]);
+/// encoder.write_composite(&item)?;
+/// #
+/// #   Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct PgComposite {
+    fields: i32,
+    buf: Vec<u8>,
+}
+
+impl PgComposite {
+    /// Creates new composite value without any fields.
+    pub fn new() -> PgComposite {
+        PgComposite::default()
+    }
+
+    /// Appends field value, which type OID is determined by the [Element](trait.Element.html) implementation.
+    pub fn field<T: Element>(&mut self, value: T) -> io::Result<&mut PgComposite> {
+        self.field_with_oid(T::OID, value)
+    }
+
+    /// Appends field value with an explicit type OID.
+    ///
+    /// Required for the user-defined field types, like enums or nested composite types,
+    /// or for the types sharing the same Rust representation, like `varchar` and `text`.
+    pub fn field_with_oid<T: Element>(&mut self, oid: u32, value: T) -> io::Result<&mut PgComposite> {
+        let len = self.buf.len();
+        self.buf.write_u32::<NetworkEndian>(oid)?;
+        if let Err(e) = value.to_writer(&mut self.buf) {
+            self.buf.truncate(len);
+            return Err(e);
+        }
+        self.fields += 1;
+
+        Ok(self)
+    }
+
+    /// Returns amount of the appended fields.
+    pub fn len(&self) -> usize {
+        self.fields as usize
+    }
+
+    /// Returns `true` if there are no fields appended.
+    pub fn is_empty(&self) -> bool {
+        self.fields == 0
+    }
+}
+
+impl Composite for PgComposite {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        if self.buf.len() > i32::MAX as usize - 4 {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "value is too large"));
+        }
+
+        writer.write_i32::<NetworkEndian>(4 + self.buf.len() as i32)?;
+        writer.write_i32::<NetworkEndian>(self.fields)?;
+        writer.write_all(&self.buf)
+    }
+}
+
+impl Composite for &PgComposite {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        Composite::to_writer(*self, writer)
+    }
+}
+
+/// Composite values are written as an anonymous `record` type by default,
+/// actual type OID should be provided for the arrays elements or the nested composite fields.
+impl Element for PgComposite {
+    const OID: u32 = oid::RECORD;
+
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        Composite::to_writer(self, writer)
+    }
+}
@@ -1,18 +1,177 @@
+use std::io;
+
+use byteorder::{WriteBytesExt, NetworkEndian};
+
+use crate::types::{Element, Date};
+
+/// Writes date from the days amount since `2000-01-01`, checking it to be in the server supported range.
+#[cfg(any(feature = "with-chrono", feature = "with-time", feature = "with-jiff"))]
+fn write_date_days<W: io::Write>(writer: &mut W, days: i32) -> io::Result<()> {
+    /// Days amount of the `4714-11-24 BC`, the lowest date supported by server.
+    const MIN_DATE: i32 = -2_451_545;
+    /// Days amount of the `5874898-01-01`, the first date not supported by server.
+    const END_DATE: i32 = 2_145_031_949;
+
+    if !(MIN_DATE..END_DATE).contains(&days) {
+        return Err(crate::types::OutOfRange::new("date").into());
+    }
+
+    writer.write_i32::<NetworkEndian>(4)?;
+    writer.write_i32::<NetworkEndian>(days)
+}
+
+/// Date value extended with the special `infinity` and `-infinity` values.
+///
+/// Wraps any `Date` implementor, which is written as is for the `PgDate::Value` variant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PgDate<T> {
+    /// Finite date value.
+    Value(T),
+    /// `infinity`, later than all other dates.
+    Infinity,
+    /// `-infinity`, earlier than all other dates.
+    NegInfinity,
+}
+
+impl<T> PgDate<T> {
+    /// Creates date from the optional value, `None` is mapped to the `infinity`.
+    pub fn or_infinity(value: Option<T>) -> PgDate<T> {
+        value.map_or(PgDate::Infinity, PgDate::Value)
+    }
+
+    /// Creates date from the optional value, `None` is mapped to the `-infinity`.
+    pub fn or_neg_infinity(value: Option<T>) -> PgDate<T> {
+        value.map_or(PgDate::NegInfinity, PgDate::Value)
+    }
+}
+
+impl<T> From<T> for PgDate<T> {
+    fn from(value: T) -> PgDate<T> {
+        PgDate::Value(value)
+    }
+}
+
+fn write_infinite_date<W: io::Write>(writer: &mut W, positive: bool) -> io::Result<()> {
+    writer.write_i32::<NetworkEndian>(4)?;
+    writer.write_i32::<NetworkEndian>(if positive { i32::MAX } else { i32::MIN })
+}
+
+impl<T: Date> Date for PgDate<T> {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        match self {
+            PgDate::Value(value) => value.to_writer(writer),
+            PgDate::Infinity => write_infinite_date(writer, true),
+            PgDate::NegInfinity => write_infinite_date(writer, false),
+        }
+    }
+}
+
+impl<T: Element> Element for PgDate<T> {
+    const OID: u32 = T::OID;
+
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        match self {
+            PgDate::Value(value) => value.to_writer(writer),
+            PgDate::Infinity => write_infinite_date(writer, true),
+            PgDate::NegInfinity => write_infinite_date(writer, false),
+        }
+    }
+}
+
 #[cfg(feature = "with-chrono")]
 mod with_chrono {
-    use std::io;
+    use super::*;
+
+    use chrono::{Datelike, NaiveDate, NaiveDateTime, DateTime, TimeZone};
+
+    use crate::types::oid;
+
+    /// Days amount from the `0001-01-01` to the `2000-01-01`.
+    const POSTGRES_EPOCH_DAYS_FROM_CE: i32 = 730_120;
+
+    fn write_date<W: io::Write, T: Datelike>(writer: &mut W, value: &T) -> io::Result<()> {
+        write_date_days(writer, value.num_days_from_ce() - POSTGRES_EPOCH_DAYS_FROM_CE)
+    }
+
+    impl Date for NaiveDate {
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            write_date(writer, self)
+        }
+    }
 
-    use chrono::Datelike;
-    use byteorder::{WriteBytesExt, NetworkEndian};
+    #[allow(deprecated)]
+    impl<Tz: TimeZone> Date for chrono::Date<Tz> {
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            write_date(writer, self)
+        }
+    }
+
+    impl Date for NaiveDateTime {
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            write_date(writer, self)
+        }
+    }
+
+    impl<Tz: TimeZone> Date for DateTime<Tz> {
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            write_date(writer, self)
+        }
+    }
+
+    impl Element for NaiveDate {
+        const OID: u32 = oid::DATE;
+
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            Date::to_writer(self, writer)
+        }
+    }
+}
+
+#[cfg(feature = "with-time")]
+mod with_time {
+    use super::*;
+
+    use crate::types::oid;
+
+    /// Julian day number of the `2000-01-01`.
+    const POSTGRES_EPOCH_JDATE: i32 = 2_451_545;
+
+    impl Date for time::Date {
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            write_date_days(writer, self.to_julian_day() - POSTGRES_EPOCH_JDATE)
+        }
+    }
+
+    impl Element for time::Date {
+        const OID: u32 = oid::DATE;
+
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            Date::to_writer(self, writer)
+        }
+    }
+}
+
+#[cfg(feature = "with-jiff")]
+mod with_jiff {
+    use super::*;
+
+    use jiff::civil::Date as JiffDate;
+
+    use crate::types::oid;
+
+    impl Date for JiffDate {
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            let days = self.duration_since(JiffDate::constant(2000, 1, 1)).as_hours() / 24;
+
+            write_date_days(writer, days as i32)
+        }
+    }
 
-    use crate::types::Date;
+    impl Element for JiffDate {
+        const OID: u32 = oid::DATE;
 
-    impl<T> Date for T where T: Datelike {
         fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
-            // TODO: Move this big number to constant with an adequate name
-            let days = self.num_days_from_ce() - 730_120;
-            writer.write_i32::<NetworkEndian>(4)?;
-            writer.write_i32::<NetworkEndian>(days)
+            Date::to_writer(self, writer)
         }
     }
 }
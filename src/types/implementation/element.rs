@@ -0,0 +1,35 @@
+use std::io;
+
+use byteorder::{WriteBytesExt, NetworkEndian};
+
+use crate::types::Element;
+
+impl<T: Element> Element for Option<T> {
+    const OID: u32 = T::OID;
+
+    fn is_null(&self) -> bool {
+        match self {
+            Some(value) => value.is_null(),
+            None => true,
+        }
+    }
+
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        match self {
+            Some(value) => value.to_writer(writer),
+            None => writer.write_i32::<NetworkEndian>(-1),
+        }
+    }
+}
+
+impl<T: Element + ?Sized> Element for &T {
+    const OID: u32 = T::OID;
+
+    fn is_null(&self) -> bool {
+        (**self).is_null()
+    }
+
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        (**self).to_writer(writer)
+    }
+}
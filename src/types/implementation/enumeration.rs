@@ -0,0 +1,26 @@
+use std::io;
+
+use byteorder::{WriteBytesExt, NetworkEndian};
+
+use crate::types::Enum;
+
+impl Enum for str {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        debug_assert!(self.len() < i32::MAX as usize);
+
+        writer.write_i32::<NetworkEndian>(self.len() as i32)?;
+        writer.write_all(self.as_bytes())
+    }
+}
+
+impl Enum for String {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        Enum::to_writer(self.as_str(), writer)
+    }
+}
+
+impl<T: Enum + ?Sized> Enum for &T {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        Enum::to_writer(*self, writer)
+    }
+}
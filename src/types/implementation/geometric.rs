@@ -0,0 +1,474 @@
+use std::io;
+
+use byteorder::{WriteBytesExt, NetworkEndian};
+
+use crate::types::{oid, Element, Point, Line, LineSegment, Rectangle, Path, Polygon, Circle};
+
+// https://github.com/postgres/postgres/blob/master/src/backend/utils/adt/geo_ops.c
+
+/// Native `point` value.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PgPoint {
+    pub x: f64,
+    pub y: f64,
+}
+
+impl PgPoint {
+    /// Creates new point.
+    pub fn new(x: f64, y: f64) -> PgPoint {
+        PgPoint {
+            x,
+            y,
+        }
+    }
+}
+
+impl From<(f64, f64)> for PgPoint {
+    fn from((x, y): (f64, f64)) -> PgPoint {
+        PgPoint::new(x, y)
+    }
+}
+
+/// Native `line` value, an infinite line represented by the `Ax + By + C = 0` linear equation.
+///
+/// `A` and `B` coefficients are not allowed to be zero both.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PgLine {
+    pub a: f64,
+    pub b: f64,
+    pub c: f64,
+}
+
+impl PgLine {
+    /// Creates new line from the linear equation coefficients.
+    pub fn new(a: f64, b: f64, c: f64) -> PgLine {
+        PgLine {
+            a,
+            b,
+            c,
+        }
+    }
+}
+
+/// Native `lseg` value, a finite line segment.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PgLineSegment {
+    pub start: PgPoint,
+    pub end: PgPoint,
+}
+
+impl PgLineSegment {
+    /// Creates new line segment from its end points.
+    pub fn new(start: PgPoint, end: PgPoint) -> PgLineSegment {
+        PgLineSegment {
+            start,
+            end,
+        }
+    }
+}
+
+/// Native `box` value, a rectangular box represented by a pair of its opposite corners.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PgBox {
+    pub high: PgPoint,
+    pub low: PgPoint,
+}
+
+impl PgBox {
+    /// Creates new box from any pair of its opposite corners.
+    pub fn new(a: PgPoint, b: PgPoint) -> PgBox {
+        PgBox {
+            high: PgPoint::new(a.x.max(b.x), a.y.max(b.y)),
+            low: PgPoint::new(a.x.min(b.x), a.y.min(b.y)),
+        }
+    }
+}
+
+/// Native `path` value, an open or closed list of connected points.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PgPath {
+    pub closed: bool,
+    pub points: Vec<PgPoint>,
+}
+
+impl PgPath {
+    /// Creates new open path.
+    pub fn open(points: Vec<PgPoint>) -> PgPath {
+        PgPath {
+            closed: false,
+            points,
+        }
+    }
+
+    /// Creates new closed path.
+    pub fn closed(points: Vec<PgPoint>) -> PgPath {
+        PgPath {
+            closed: true,
+            points,
+        }
+    }
+}
+
+/// Native `polygon` value, represented by the list of its vertices.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PgPolygon {
+    pub points: Vec<PgPoint>,
+}
+
+impl PgPolygon {
+    /// Creates new polygon from its vertices.
+    pub fn new(points: Vec<PgPoint>) -> PgPolygon {
+        PgPolygon {
+            points,
+        }
+    }
+}
+
+/// Native `circle` value.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PgCircle {
+    pub center: PgPoint,
+    pub radius: f64,
+}
+
+impl PgCircle {
+    /// Creates new circle.
+    pub fn new(center: PgPoint, radius: f64) -> PgCircle {
+        PgCircle {
+            center,
+            radius,
+        }
+    }
+}
+
+fn invalid_input(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, message)
+}
+
+fn write_point<W: io::Write>(writer: &mut W, point: &PgPoint) -> io::Result<()> {
+    writer.write_f64::<NetworkEndian>(point.x)?;
+    writer.write_f64::<NetworkEndian>(point.y)
+}
+
+/// Writes the points list, prefixed with the data length and the `prefix` bytes.
+pub(crate) fn write_points<W, I>(writer: &mut W, prefix: &[u8], points: I) -> io::Result<()>
+        where W: io::Write, I: ExactSizeIterator<Item = PgPoint> {
+    if points.len() == 0 {
+        return Err(invalid_input("invalid number of points: at least one point is required"));
+    }
+    if points.len() > (i32::MAX as usize - prefix.len() - 4) / 16 {
+        return Err(invalid_input("invalid number of points: too many points"));
+    }
+
+    writer.write_i32::<NetworkEndian>((prefix.len() + 4 + 16 * points.len()) as i32)?;
+    writer.write_all(prefix)?;
+    writer.write_i32::<NetworkEndian>(points.len() as i32)?;
+    for point in points {
+        write_point(writer, &point)?;
+    }
+
+    Ok(())
+}
+
+impl Point for PgPoint {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        writer.write_i32::<NetworkEndian>(16)?;
+        write_point(writer, self)
+    }
+}
+
+impl Element for PgPoint {
+    const OID: u32 = oid::POINT;
+
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        Point::to_writer(self, writer)
+    }
+}
+
+impl Point for (f64, f64) {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        Point::to_writer(&PgPoint::from(*self), writer)
+    }
+}
+
+impl Line for PgLine {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        if self.a == 0.0 && self.b == 0.0 {
+            return Err(invalid_input("invalid line specification: A and B cannot both be zero"));
+        }
+
+        writer.write_i32::<NetworkEndian>(24)?;
+        writer.write_f64::<NetworkEndian>(self.a)?;
+        writer.write_f64::<NetworkEndian>(self.b)?;
+        writer.write_f64::<NetworkEndian>(self.c)
+    }
+}
+
+impl Element for PgLine {
+    const OID: u32 = oid::LINE;
+
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        Line::to_writer(self, writer)
+    }
+}
+
+/// `(A, B, C)` coefficients of the `Ax + By + C = 0` linear equation.
+impl Line for (f64, f64, f64) {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        Line::to_writer(&PgLine::new(self.0, self.1, self.2), writer)
+    }
+}
+
+impl LineSegment for PgLineSegment {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        writer.write_i32::<NetworkEndian>(32)?;
+        write_point(writer, &self.start)?;
+        write_point(writer, &self.end)
+    }
+}
+
+impl Element for PgLineSegment {
+    const OID: u32 = oid::LSEG;
+
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        LineSegment::to_writer(self, writer)
+    }
+}
+
+/// Start and end points of the segment.
+impl LineSegment for ((f64, f64), (f64, f64)) {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        LineSegment::to_writer(&PgLineSegment::new(self.0.into(), self.1.into()), writer)
+    }
+}
+
+impl Rectangle for PgBox {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        writer.write_i32::<NetworkEndian>(32)?;
+        write_point(writer, &self.high)?;
+        write_point(writer, &self.low)
+    }
+}
+
+impl Element for PgBox {
+    const OID: u32 = oid::BOX;
+
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        Rectangle::to_writer(self, writer)
+    }
+}
+
+/// Any pair of the opposite box corners.
+impl Rectangle for ((f64, f64), (f64, f64)) {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        Rectangle::to_writer(&PgBox::new(self.0.into(), self.1.into()), writer)
+    }
+}
+
+impl Path for PgPath {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        write_points(writer, &[self.closed as u8], self.points.iter().copied())
+    }
+}
+
+impl Element for PgPath {
+    const OID: u32 = oid::PATH;
+
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        Path::to_writer(self, writer)
+    }
+}
+
+impl Path for &PgPath {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        Path::to_writer(*self, writer)
+    }
+}
+
+impl Polygon for PgPolygon {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        write_points(writer, &[], self.points.iter().copied())
+    }
+}
+
+impl Element for PgPolygon {
+    const OID: u32 = oid::POLYGON;
+
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        Polygon::to_writer(self, writer)
+    }
+}
+
+impl Polygon for &PgPolygon {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        Polygon::to_writer(*self, writer)
+    }
+}
+
+/// Polygon vertices.
+impl Polygon for &[(f64, f64)] {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        write_points(writer, &[], self.iter().copied().map(PgPoint::from))
+    }
+}
+
+/// Polygon vertices.
+impl Polygon for Vec<(f64, f64)> {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        Polygon::to_writer(&self.as_slice(), writer)
+    }
+}
+
+impl Circle for PgCircle {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        if self.radius < 0.0 {
+            return Err(invalid_input("invalid radius in external circle value"));
+        }
+
+        writer.write_i32::<NetworkEndian>(24)?;
+        write_point(writer, &self.center)?;
+        writer.write_f64::<NetworkEndian>(self.radius)
+    }
+}
+
+impl Element for PgCircle {
+    const OID: u32 = oid::CIRCLE;
+
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        Circle::to_writer(self, writer)
+    }
+}
+
+/// Center point and radius of the circle.
+impl Circle for ((f64, f64), f64) {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        Circle::to_writer(&PgCircle::new(self.0.into(), self.1), writer)
+    }
+}
+
+#[cfg(feature = "with-geo-types")]
+mod with_geo_types {
+    use super::*;
+
+    use geo_types::{Coord, LineString, Rect};
+
+    impl From<Coord<f64>> for PgPoint {
+        fn from(coord: Coord<f64>) -> PgPoint {
+            PgPoint::new(coord.x, coord.y)
+        }
+    }
+
+    /// Returns ring coordinates without the closing one, as PostgreSQL closed paths and polygons
+    /// do not repeat the first point at the end.
+    fn ring(line: &LineString<f64>) -> &[Coord<f64>] {
+        match line.0.split_last() {
+            Some((_, rest)) if line.is_closed() && !rest.is_empty() => rest,
+            _ => &line.0,
+        }
+    }
+
+    impl Point for Coord<f64> {
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            Point::to_writer(&PgPoint::from(*self), writer)
+        }
+    }
+
+    impl Point for geo_types::Point<f64> {
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            Point::to_writer(&PgPoint::from(self.0), writer)
+        }
+    }
+
+    impl LineSegment for geo_types::Line<f64> {
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            LineSegment::to_writer(&PgLineSegment::new(self.start.into(), self.end.into()), writer)
+        }
+    }
+
+    impl Rectangle for Rect<f64> {
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            Rectangle::to_writer(&PgBox::new(self.max().into(), self.min().into()), writer)
+        }
+    }
+
+    /// Closed line strings are written as a closed paths, all the other ones as an open paths.
+    impl Path for LineString<f64> {
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            let closed = self.is_closed() && self.0.len() > 1;
+
+            write_points(writer, &[closed as u8], ring(self).iter().copied().map(PgPoint::from))
+        }
+    }
+
+    impl Path for &LineString<f64> {
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            Path::to_writer(*self, writer)
+        }
+    }
+
+    /// Only polygons without interior rings can be written, as `polygon` type has no holes support.
+    impl Polygon for geo_types::Polygon<f64> {
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            if !self.interiors().is_empty() {
+                return Err(invalid_input("polygon with interior rings can not be represented by the polygon type"));
+            }
+
+            write_points(writer, &[], ring(self.exterior()).iter().copied().map(PgPoint::from))
+        }
+    }
+
+    impl Polygon for &geo_types::Polygon<f64> {
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            Polygon::to_writer(*self, writer)
+        }
+    }
+
+    // Geometries are array elements of the built-in geometric types, as PostGIS types OIDs are not known in advance
+    impl Element for Coord<f64> {
+        const OID: u32 = oid::POINT;
+
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            Point::to_writer(self, writer)
+        }
+    }
+
+    impl Element for geo_types::Point<f64> {
+        const OID: u32 = oid::POINT;
+
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            Point::to_writer(self, writer)
+        }
+    }
+
+    impl Element for geo_types::Line<f64> {
+        const OID: u32 = oid::LSEG;
+
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            LineSegment::to_writer(self, writer)
+        }
+    }
+
+    impl Element for Rect<f64> {
+        const OID: u32 = oid::BOX;
+
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            Rectangle::to_writer(self, writer)
+        }
+    }
+
+    impl Element for LineString<f64> {
+        const OID: u32 = oid::PATH;
+
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            Path::to_writer(self, writer)
+        }
+    }
+
+    impl Element for geo_types::Polygon<f64> {
+        const OID: u32 = oid::POLYGON;
+
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            Polygon::to_writer(self, writer)
+        }
+    }
+}
@@ -0,0 +1,460 @@
+use std::io;
+
+use byteorder::{WriteBytesExt, NetworkEndian};
+
+use crate::types::Geometry;
+use super::write_length_prefixed;
+
+// https://github.com/postgis/postgis/blob/master/doc/ZMSGeoms.txt
+
+/// Big-endian (XDR) byte order marker.
+const WKB_XDR: u8 = 0;
+
+const WKB_POINT: u32 = 1;
+const WKB_LINESTRING: u32 = 2;
+const WKB_POLYGON: u32 = 3;
+const WKB_MULTIPOINT: u32 = 4;
+const WKB_MULTILINESTRING: u32 = 5;
+const WKB_MULTIPOLYGON: u32 = 6;
+const WKB_GEOMETRYCOLLECTION: u32 = 7;
+
+const EWKB_Z_FLAG: u32 = 0x8000_0000;
+const EWKB_M_FLAG: u32 = 0x4000_0000;
+const EWKB_SRID_FLAG: u32 = 0x2000_0000;
+
+/// Coordinate of the PostGIS geometry, with optional `z` and `m` dimensions.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PgCoord {
+    pub x: f64,
+    pub y: f64,
+    pub z: Option<f64>,
+    pub m: Option<f64>,
+}
+
+impl PgCoord {
+    /// Creates new two-dimensional coordinate.
+    pub fn new(x: f64, y: f64) -> PgCoord {
+        PgCoord {
+            x,
+            y,
+            z: None,
+            m: None,
+        }
+    }
+
+    /// Creates new coordinate with the `z` dimension.
+    pub fn xyz(x: f64, y: f64, z: f64) -> PgCoord {
+        PgCoord {
+            z: Some(z),
+            ..PgCoord::new(x, y)
+        }
+    }
+
+    /// Creates new coordinate with the `m` dimension.
+    pub fn xym(x: f64, y: f64, m: f64) -> PgCoord {
+        PgCoord {
+            m: Some(m),
+            ..PgCoord::new(x, y)
+        }
+    }
+
+    /// Creates new coordinate with both `z` and `m` dimensions.
+    pub fn xyzm(x: f64, y: f64, z: f64, m: f64) -> PgCoord {
+        PgCoord {
+            z: Some(z),
+            m: Some(m),
+            ..PgCoord::new(x, y)
+        }
+    }
+}
+
+impl From<(f64, f64)> for PgCoord {
+    fn from((x, y): (f64, f64)) -> PgCoord {
+        PgCoord::new(x, y)
+    }
+}
+
+/// Native PostGIS geometry value.
+///
+/// All the coordinates of the geometry are required to have the same dimensions.
+/// Polygon rings are required to be closed and the first ring is an exterior one.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PgGeometry {
+    Point(PgCoord),
+    LineString(Vec<PgCoord>),
+    Polygon(Vec<Vec<PgCoord>>),
+    MultiPoint(Vec<PgCoord>),
+    MultiLineString(Vec<Vec<PgCoord>>),
+    MultiPolygon(Vec<Vec<Vec<PgCoord>>>),
+    GeometryCollection(Vec<PgGeometry>),
+}
+
+impl PgGeometry {
+    fn first_coord(&self) -> Option<&PgCoord> {
+        match self {
+            PgGeometry::Point(coord) => Some(coord),
+            PgGeometry::LineString(coords) | PgGeometry::MultiPoint(coords) => coords.first(),
+            PgGeometry::Polygon(lines) | PgGeometry::MultiLineString(lines) => lines.iter().flatten().next(),
+            PgGeometry::MultiPolygon(polygons) => polygons.iter().flatten().flatten().next(),
+            PgGeometry::GeometryCollection(geometries) => geometries.iter().find_map(PgGeometry::first_coord),
+        }
+    }
+
+    fn write_shape(&self, buf: &mut Vec<u8>, dims: Dims, srid: Option<i32>) -> io::Result<()> {
+        match self {
+            PgGeometry::Point(coord) => {
+                write_header(buf, WKB_POINT, dims, srid)?;
+                write_coord(buf, dims, *coord)
+            },
+            PgGeometry::LineString(coords) => {
+                write_header(buf, WKB_LINESTRING, dims, srid)?;
+                write_line_string(buf, dims, coords)
+            },
+            PgGeometry::Polygon(rings) => {
+                write_header(buf, WKB_POLYGON, dims, srid)?;
+                write_polygon(buf, dims, rings)
+            },
+            PgGeometry::MultiPoint(coords) => {
+                write_header(buf, WKB_MULTIPOINT, dims, srid)?;
+                write_count(buf, coords.len())?;
+                for coord in coords {
+                    write_header(buf, WKB_POINT, dims, None)?;
+                    write_coord(buf, dims, *coord)?;
+                }
+                Ok(())
+            },
+            PgGeometry::MultiLineString(lines) => {
+                write_header(buf, WKB_MULTILINESTRING, dims, srid)?;
+                write_count(buf, lines.len())?;
+                for coords in lines {
+                    write_header(buf, WKB_LINESTRING, dims, None)?;
+                    write_line_string(buf, dims, coords)?;
+                }
+                Ok(())
+            },
+            PgGeometry::MultiPolygon(polygons) => {
+                write_header(buf, WKB_MULTIPOLYGON, dims, srid)?;
+                write_count(buf, polygons.len())?;
+                for rings in polygons {
+                    write_header(buf, WKB_POLYGON, dims, None)?;
+                    write_polygon(buf, dims, rings)?;
+                }
+                Ok(())
+            },
+            PgGeometry::GeometryCollection(geometries) => {
+                write_header(buf, WKB_GEOMETRYCOLLECTION, dims, srid)?;
+                write_count(buf, geometries.len())?;
+                for geometry in geometries {
+                    geometry.write_shape(buf, dims, None)?;
+                }
+                Ok(())
+            },
+        }
+    }
+}
+
+/// Geometry with the spatial reference system identifier attached, written as an EWKB with SRID.
+///
+/// Can be used both with the native `PgGeometry` and with `geo-types` geometries.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PgSrid<G> {
+    pub srid: i32,
+    pub geometry: G,
+}
+
+impl<G> PgSrid<G> {
+    /// Attaches `srid` to the `geometry`.
+    pub fn new(srid: i32, geometry: G) -> PgSrid<G> {
+        PgSrid {
+            srid,
+            geometry,
+        }
+    }
+}
+
+/// Geometry types which can be written in the EWKB format.
+pub(crate) trait Ewkb {
+    fn write_ewkb(&self, buf: &mut Vec<u8>, srid: Option<i32>) -> io::Result<()>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Dims {
+    z: bool,
+    m: bool,
+}
+
+impl Dims {
+    const XY: Dims = Dims { z: false, m: false };
+
+    fn of(coord: &PgCoord) -> Dims {
+        Dims {
+            z: coord.z.is_some(),
+            m: coord.m.is_some(),
+        }
+    }
+}
+
+fn invalid_input(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, message)
+}
+
+fn write_header(buf: &mut Vec<u8>, kind: u32, dims: Dims, srid: Option<i32>) -> io::Result<()> {
+    let mut kind = kind;
+    if dims.z {
+        kind |= EWKB_Z_FLAG;
+    }
+    if dims.m {
+        kind |= EWKB_M_FLAG;
+    }
+    if srid.is_some() {
+        kind |= EWKB_SRID_FLAG;
+    }
+
+    buf.write_u8(WKB_XDR)?;
+    buf.write_u32::<NetworkEndian>(kind)?;
+    if let Some(srid) = srid {
+        buf.write_i32::<NetworkEndian>(srid)?;
+    }
+
+    Ok(())
+}
+
+fn write_count(buf: &mut Vec<u8>, count: usize) -> io::Result<()> {
+    if count > u32::MAX as usize {
+        return Err(invalid_input("geometry has too many elements"));
+    }
+
+    buf.write_u32::<NetworkEndian>(count as u32)
+}
+
+fn write_coord<C: Into<PgCoord>>(buf: &mut Vec<u8>, dims: Dims, coord: C) -> io::Result<()> {
+    let coord = coord.into();
+    if Dims::of(&coord) != dims {
+        return Err(invalid_input("geometry has mixed coordinate dimensions"));
+    }
+
+    buf.write_f64::<NetworkEndian>(coord.x)?;
+    buf.write_f64::<NetworkEndian>(coord.y)?;
+    if let Some(z) = coord.z {
+        buf.write_f64::<NetworkEndian>(z)?;
+    }
+    if let Some(m) = coord.m {
+        buf.write_f64::<NetworkEndian>(m)?;
+    }
+
+    Ok(())
+}
+
+fn write_coords<C: Into<PgCoord> + Copy>(buf: &mut Vec<u8>, dims: Dims, coords: &[C]) -> io::Result<()> {
+    write_count(buf, coords.len())?;
+    for coord in coords {
+        write_coord(buf, dims, *coord)?;
+    }
+
+    Ok(())
+}
+
+/// Writes line string points, non-empty line string should have at least two points.
+fn write_line_string<C: Into<PgCoord> + Copy>(buf: &mut Vec<u8>, dims: Dims, coords: &[C]) -> io::Result<()> {
+    if coords.len() == 1 {
+        return Err(invalid_input("geometry requires more points: line string should have at least two points"));
+    }
+
+    write_coords(buf, dims, coords)
+}
+
+/// Writes polygon ring points, non-empty ring should be closed and have at least four points.
+fn write_ring<C: Into<PgCoord> + Copy>(buf: &mut Vec<u8>, dims: Dims, coords: &[C]) -> io::Result<()> {
+    if let (Some(first), Some(last)) = (coords.first(), coords.last()) {
+        if coords.len() < 4 {
+            return Err(invalid_input("geometry requires more points: polygon ring should have at least four points"));
+        }
+        if (*first).into() != (*last).into() {
+            return Err(invalid_input("geometry contains non-closed rings"));
+        }
+    }
+
+    write_coords(buf, dims, coords)
+}
+
+fn write_polygon(buf: &mut Vec<u8>, dims: Dims, rings: &[Vec<PgCoord>]) -> io::Result<()> {
+    write_count(buf, rings.len())?;
+    for ring in rings {
+        write_ring(buf, dims, ring)?;
+    }
+
+    Ok(())
+}
+
+impl Ewkb for PgGeometry {
+    fn write_ewkb(&self, buf: &mut Vec<u8>, srid: Option<i32>) -> io::Result<()> {
+        let dims = self.first_coord().map(Dims::of).unwrap_or(Dims::XY);
+
+        self.write_shape(buf, dims, srid)
+    }
+}
+
+impl<T: Geometry + ?Sized> Geometry for &T {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        (**self).to_writer(writer)
+    }
+}
+
+macro_rules! impl_geometry {
+    ($($t:ty),*) => {
+        $(
+            impl Geometry for $t {
+                fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+                    write_length_prefixed(writer, |buf| self.write_ewkb(buf, None))
+                }
+            }
+
+            impl Geometry for PgSrid<$t> {
+                fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+                    write_length_prefixed(writer, |buf| self.geometry.write_ewkb(buf, Some(self.srid)))
+                }
+            }
+
+            impl Geometry for PgSrid<&$t> {
+                fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+                    write_length_prefixed(writer, |buf| self.geometry.write_ewkb(buf, Some(self.srid)))
+                }
+            }
+        )*
+    };
+}
+
+impl_geometry!(PgGeometry);
+
+#[cfg(feature = "with-geo-types")]
+mod with_geo_types {
+    use super::*;
+
+    use geo_types::{Coord, Line, LineString, MultiLineString, MultiPoint, MultiPolygon, Point, Polygon, Rect,
+        Triangle, GeometryCollection};
+
+    impl From<Coord<f64>> for PgCoord {
+        fn from(coord: Coord<f64>) -> PgCoord {
+            PgCoord::new(coord.x, coord.y)
+        }
+    }
+
+    fn write_point(buf: &mut Vec<u8>, point: &Point<f64>, srid: Option<i32>) -> io::Result<()> {
+        write_header(buf, WKB_POINT, Dims::XY, srid)?;
+        write_coord(buf, Dims::XY, point.0)
+    }
+
+    fn write_line(buf: &mut Vec<u8>, line: &LineString<f64>, srid: Option<i32>) -> io::Result<()> {
+        write_header(buf, WKB_LINESTRING, Dims::XY, srid)?;
+        write_line_string(buf, Dims::XY, &line.0)
+    }
+
+    fn write_polygon(buf: &mut Vec<u8>, polygon: &Polygon<f64>, srid: Option<i32>) -> io::Result<()> {
+        write_header(buf, WKB_POLYGON, Dims::XY, srid)?;
+        if polygon.exterior().0.is_empty() {
+            return write_count(buf, 0);
+        }
+
+        write_count(buf, 1 + polygon.interiors().len())?;
+        write_ring(buf, Dims::XY, &polygon.exterior().0)?;
+        for interior in polygon.interiors() {
+            write_ring(buf, Dims::XY, &interior.0)?;
+        }
+
+        Ok(())
+    }
+
+    fn write_collection<T: Ewkb>(buf: &mut Vec<u8>, kind: u32, items: &[T], srid: Option<i32>) -> io::Result<()> {
+        write_header(buf, kind, Dims::XY, srid)?;
+        write_count(buf, items.len())?;
+        for item in items {
+            item.write_ewkb(buf, None)?;
+        }
+
+        Ok(())
+    }
+
+    impl Ewkb for Point<f64> {
+        fn write_ewkb(&self, buf: &mut Vec<u8>, srid: Option<i32>) -> io::Result<()> {
+            write_point(buf, self, srid)
+        }
+    }
+
+    /// Line is written as a line string of its two points.
+    impl Ewkb for Line<f64> {
+        fn write_ewkb(&self, buf: &mut Vec<u8>, srid: Option<i32>) -> io::Result<()> {
+            write_header(buf, WKB_LINESTRING, Dims::XY, srid)?;
+            write_coords(buf, Dims::XY, &[self.start, self.end])
+        }
+    }
+
+    impl Ewkb for LineString<f64> {
+        fn write_ewkb(&self, buf: &mut Vec<u8>, srid: Option<i32>) -> io::Result<()> {
+            write_line(buf, self, srid)
+        }
+    }
+
+    impl Ewkb for Polygon<f64> {
+        fn write_ewkb(&self, buf: &mut Vec<u8>, srid: Option<i32>) -> io::Result<()> {
+            write_polygon(buf, self, srid)
+        }
+    }
+
+    /// Rectangle is written as a polygon.
+    impl Ewkb for Rect<f64> {
+        fn write_ewkb(&self, buf: &mut Vec<u8>, srid: Option<i32>) -> io::Result<()> {
+            write_polygon(buf, &self.to_polygon(), srid)
+        }
+    }
+
+    /// Triangle is written as a polygon.
+    impl Ewkb for Triangle<f64> {
+        fn write_ewkb(&self, buf: &mut Vec<u8>, srid: Option<i32>) -> io::Result<()> {
+            write_polygon(buf, &self.to_polygon(), srid)
+        }
+    }
+
+    impl Ewkb for MultiPoint<f64> {
+        fn write_ewkb(&self, buf: &mut Vec<u8>, srid: Option<i32>) -> io::Result<()> {
+            write_collection(buf, WKB_MULTIPOINT, &self.0, srid)
+        }
+    }
+
+    impl Ewkb for MultiLineString<f64> {
+        fn write_ewkb(&self, buf: &mut Vec<u8>, srid: Option<i32>) -> io::Result<()> {
+            write_collection(buf, WKB_MULTILINESTRING, &self.0, srid)
+        }
+    }
+
+    impl Ewkb for MultiPolygon<f64> {
+        fn write_ewkb(&self, buf: &mut Vec<u8>, srid: Option<i32>) -> io::Result<()> {
+            write_collection(buf, WKB_MULTIPOLYGON, &self.0, srid)
+        }
+    }
+
+    impl Ewkb for GeometryCollection<f64> {
+        fn write_ewkb(&self, buf: &mut Vec<u8>, srid: Option<i32>) -> io::Result<()> {
+            write_collection(buf, WKB_GEOMETRYCOLLECTION, &self.0, srid)
+        }
+    }
+
+    impl Ewkb for geo_types::Geometry<f64> {
+        fn write_ewkb(&self, buf: &mut Vec<u8>, srid: Option<i32>) -> io::Result<()> {
+            match self {
+                geo_types::Geometry::Point(geometry) => geometry.write_ewkb(buf, srid),
+                geo_types::Geometry::Line(geometry) => geometry.write_ewkb(buf, srid),
+                geo_types::Geometry::LineString(geometry) => geometry.write_ewkb(buf, srid),
+                geo_types::Geometry::Polygon(geometry) => geometry.write_ewkb(buf, srid),
+                geo_types::Geometry::MultiPoint(geometry) => geometry.write_ewkb(buf, srid),
+                geo_types::Geometry::MultiLineString(geometry) => geometry.write_ewkb(buf, srid),
+                geo_types::Geometry::MultiPolygon(geometry) => geometry.write_ewkb(buf, srid),
+                geo_types::Geometry::GeometryCollection(geometry) => geometry.write_ewkb(buf, srid),
+                geo_types::Geometry::Rect(geometry) => geometry.write_ewkb(buf, srid),
+                geo_types::Geometry::Triangle(geometry) => geometry.write_ewkb(buf, srid),
+            }
+        }
+    }
+
+    impl_geometry!(Point<f64>, Line<f64>, LineString<f64>, Polygon<f64>, Rect<f64>, Triangle<f64>, MultiPoint<f64>,
+        MultiLineString<f64>, MultiPolygon<f64>, GeometryCollection<f64>, geo_types::Geometry<f64>);
+}
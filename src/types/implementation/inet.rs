@@ -0,0 +1,275 @@
+use std::io;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use byteorder::{WriteBytesExt, NetworkEndian};
+
+use crate::types::{oid, Element, Inet, Cidr};
+
+// https://github.com/postgres/postgres/blob/master/src/backend/utils/adt/network.c
+
+const PGSQL_AF_INET: u8 = 2;
+const PGSQL_AF_INET6: u8 = 3;
+
+/// Writes network address with `bits` netmask length.
+///
+/// For `cidr` values address is checked to have no bits set to the right of the netmask,
+/// same as the server does it.
+pub(crate) fn write_network<W: io::Write>(writer: &mut W, address: IpAddr, bits: u8, is_cidr: bool) -> io::Result<()> {
+    let (family, octets) = match address {
+        IpAddr::V4(address) => (PGSQL_AF_INET, address.octets().to_vec()),
+        IpAddr::V6(address) => (PGSQL_AF_INET6, address.octets().to_vec()),
+    };
+    let max_bits = octets.len() * 8;
+
+    if usize::from(bits) > max_bits {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("invalid netmask length {} for {} address", bits, address),
+        ));
+    }
+    if is_cidr {
+        let host_bits_set = octets.iter().enumerate().any(|(idx, byte)| {
+            let network_bits = usize::from(bits).saturating_sub(idx * 8).min(8);
+            byte & (0xffu8.checked_shr(network_bits as u32).unwrap_or(0)) != 0
+        });
+        if host_bits_set {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("invalid cidr value {}/{}: value has bits set to right of mask", address, bits),
+            ));
+        }
+    }
+
+    writer.write_i32::<NetworkEndian>(4 + octets.len() as i32)?;
+    writer.write_u8(family)?;
+    writer.write_u8(bits)?;
+    writer.write_u8(is_cidr as u8)?;
+    writer.write_u8(octets.len() as u8)?;
+    writer.write_all(&octets)
+}
+
+fn max_bits(address: IpAddr) -> u8 {
+    match address {
+        IpAddr::V4(..) => 32,
+        IpAddr::V6(..) => 128,
+    }
+}
+
+impl Inet for IpAddr {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        write_network(writer, *self, max_bits(*self), false)
+    }
+}
+
+impl Inet for Ipv4Addr {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        write_network(writer, IpAddr::V4(*self), 32, false)
+    }
+}
+
+impl Inet for Ipv6Addr {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        write_network(writer, IpAddr::V6(*self), 128, false)
+    }
+}
+
+/// Host address with a netmask length, as in `192.168.0.1/24`.
+impl Inet for (IpAddr, u8) {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        write_network(writer, self.0, self.1, false)
+    }
+}
+
+/// Single host network.
+impl Cidr for IpAddr {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        write_network(writer, *self, max_bits(*self), true)
+    }
+}
+
+/// Single host network.
+impl Cidr for Ipv4Addr {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        write_network(writer, IpAddr::V4(*self), 32, true)
+    }
+}
+
+/// Single host network.
+impl Cidr for Ipv6Addr {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        write_network(writer, IpAddr::V6(*self), 128, true)
+    }
+}
+
+/// Network address with a netmask length, as in `192.168.0.0/24`.
+impl Cidr for (IpAddr, u8) {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        write_network(writer, self.0, self.1, true)
+    }
+}
+
+impl Element for IpAddr {
+    const OID: u32 = oid::INET;
+
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        Inet::to_writer(self, writer)
+    }
+}
+
+impl Element for Ipv4Addr {
+    const OID: u32 = oid::INET;
+
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        Inet::to_writer(self, writer)
+    }
+}
+
+impl Element for Ipv6Addr {
+    const OID: u32 = oid::INET;
+
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        Inet::to_writer(self, writer)
+    }
+}
+
+#[cfg(feature = "with-ipnetwork")]
+mod with_ipnetwork {
+    use std::io;
+    use std::net::IpAddr;
+
+    use ipnetwork::{IpNetwork, Ipv4Network, Ipv6Network};
+
+    use super::write_network;
+    use crate::types::{oid, Element, Inet, Cidr};
+
+    impl Inet for IpNetwork {
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            write_network(writer, self.ip(), self.prefix(), false)
+        }
+    }
+
+    impl Inet for Ipv4Network {
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            write_network(writer, IpAddr::V4(self.ip()), self.prefix(), false)
+        }
+    }
+
+    impl Inet for Ipv6Network {
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            write_network(writer, IpAddr::V6(self.ip()), self.prefix(), false)
+        }
+    }
+
+    impl Cidr for IpNetwork {
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            write_network(writer, self.ip(), self.prefix(), true)
+        }
+    }
+
+    impl Cidr for Ipv4Network {
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            write_network(writer, IpAddr::V4(self.ip()), self.prefix(), true)
+        }
+    }
+
+    impl Cidr for Ipv6Network {
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            write_network(writer, IpAddr::V6(self.ip()), self.prefix(), true)
+        }
+    }
+
+    impl Element for IpNetwork {
+        const OID: u32 = oid::INET;
+
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            Inet::to_writer(self, writer)
+        }
+    }
+
+    impl Element for Ipv4Network {
+        const OID: u32 = oid::INET;
+
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            Inet::to_writer(self, writer)
+        }
+    }
+
+    impl Element for Ipv6Network {
+        const OID: u32 = oid::INET;
+
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            Inet::to_writer(self, writer)
+        }
+    }
+}
+
+#[cfg(feature = "with-ipnet")]
+mod with_ipnet {
+    use std::io;
+    use std::net::IpAddr;
+
+    use ipnet::{IpNet, Ipv4Net, Ipv6Net};
+
+    use super::write_network;
+    use crate::types::{oid, Element, Inet, Cidr};
+
+    impl Inet for IpNet {
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            write_network(writer, self.addr(), self.prefix_len(), false)
+        }
+    }
+
+    impl Inet for Ipv4Net {
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            write_network(writer, IpAddr::V4(self.addr()), self.prefix_len(), false)
+        }
+    }
+
+    impl Inet for Ipv6Net {
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            write_network(writer, IpAddr::V6(self.addr()), self.prefix_len(), false)
+        }
+    }
+
+    impl Cidr for IpNet {
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            write_network(writer, self.addr(), self.prefix_len(), true)
+        }
+    }
+
+    impl Cidr for Ipv4Net {
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            write_network(writer, IpAddr::V4(self.addr()), self.prefix_len(), true)
+        }
+    }
+
+    impl Cidr for Ipv6Net {
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            write_network(writer, IpAddr::V6(self.addr()), self.prefix_len(), true)
+        }
+    }
+
+    impl Element for IpNet {
+        const OID: u32 = oid::INET;
+
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            Inet::to_writer(self, writer)
+        }
+    }
+
+    impl Element for Ipv4Net {
+        const OID: u32 = oid::INET;
+
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            Inet::to_writer(self, writer)
+        }
+    }
+
+    impl Element for Ipv6Net {
+        const OID: u32 = oid::INET;
+
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            Inet::to_writer(self, writer)
+        }
+    }
+}
@@ -0,0 +1,155 @@
+use std::io;
+use std::time::Duration;
+
+use byteorder::{WriteBytesExt, NetworkEndian};
+
+use crate::types::{oid, Element, Interval};
+
+/// Native `interval` value.
+///
+/// PostgreSQL keeps months, days and microseconds separately,
+/// because neither the amount of days in month nor the amount of seconds in day are constant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct PgInterval {
+    /// Months amount, including years.
+    pub months: i32,
+    /// Days amount.
+    pub days: i32,
+    /// Microseconds amount, including hours, minutes and seconds.
+    pub micros: i64,
+}
+
+impl PgInterval {
+    /// Creates new interval from its parts.
+    pub fn new(months: i32, days: i32, micros: i64) -> PgInterval {
+        PgInterval {
+            months,
+            days,
+            micros,
+        }
+    }
+}
+
+impl Interval for PgInterval {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        writer.write_i32::<NetworkEndian>(16)?;
+        writer.write_i64::<NetworkEndian>(self.micros)?;
+        writer.write_i32::<NetworkEndian>(self.days)?;
+        writer.write_i32::<NetworkEndian>(self.months)
+    }
+}
+
+impl Element for PgInterval {
+    const OID: u32 = oid::INTERVAL;
+
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        Interval::to_writer(self, writer)
+    }
+}
+
+fn overflow() -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, "duration overflows interval microseconds field")
+}
+
+/// Duration is written as microseconds amount only, sub-microsecond part is truncated.
+impl Interval for Duration {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        if self.as_micros() > i64::MAX as u128 {
+            return Err(overflow());
+        }
+
+        Interval::to_writer(&PgInterval::new(0, 0, self.as_micros() as i64), writer)
+    }
+}
+
+impl Element for Duration {
+    const OID: u32 = oid::INTERVAL;
+
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        Interval::to_writer(self, writer)
+    }
+}
+
+#[cfg(feature = "with-chrono")]
+mod with_chrono {
+    use super::*;
+
+    /// Duration is written as microseconds amount only, sub-microsecond part is truncated.
+    impl Interval for chrono::Duration {
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            let micros = self.num_microseconds().ok_or_else(overflow)?;
+
+            Interval::to_writer(&PgInterval::new(0, 0, micros), writer)
+        }
+    }
+
+    impl Element for chrono::Duration {
+        const OID: u32 = oid::INTERVAL;
+
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            Interval::to_writer(self, writer)
+        }
+    }
+}
+
+#[cfg(feature = "with-time")]
+mod with_time {
+    use std::convert::TryFrom;
+
+    use super::*;
+
+    /// Duration is written as microseconds amount only, sub-microsecond part is truncated.
+    impl Interval for time::Duration {
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            let micros = i64::try_from(self.whole_microseconds()).map_err(|_| overflow())?;
+
+            Interval::to_writer(&PgInterval::new(0, 0, micros), writer)
+        }
+    }
+
+    impl Element for time::Duration {
+        const OID: u32 = oid::INTERVAL;
+
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            Interval::to_writer(self, writer)
+        }
+    }
+}
+
+#[cfg(feature = "with-jiff")]
+mod with_jiff {
+    use super::*;
+
+    use jiff::Span;
+
+    /// Span units are kept in the same interval fields as the server does:
+    /// years and months as months, weeks and days as days and the rest of them as microseconds.
+    /// Sub-microsecond part is truncated.
+    impl Interval for Span {
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            // Span units are limited enough to never overflow months and days fields
+            let months = i32::from(self.get_years()) * 12 + self.get_months();
+            let days = self.get_weeks() * 7 + self.get_days();
+            let micros = [
+                (i64::from(self.get_hours()), 60 * 60 * 1_000 * 1_000),
+                (self.get_minutes(), 60 * 1_000 * 1_000),
+                (self.get_seconds(), 1_000 * 1_000),
+                (self.get_milliseconds(), 1_000),
+                (self.get_microseconds(), 1),
+                (self.get_nanoseconds() / 1_000, 1),
+            ].iter().try_fold(0i64, |total, (value, unit)| {
+                value.checked_mul(*unit).and_then(|value| total.checked_add(value))
+            }).ok_or_else(overflow)?;
+
+            Interval::to_writer(&PgInterval::new(months, days, micros), writer)
+        }
+    }
+
+    impl Element for Span {
+        const OID: u32 = oid::INTERVAL;
+
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            Interval::to_writer(self, writer)
+        }
+    }
+}
@@ -0,0 +1,260 @@
+use std::io;
+
+use byteorder::{WriteBytesExt, NetworkEndian};
+
+use crate::types::{oid, Element, Json, Jsonb};
+
+/// `jsonb` binary format version, the only one known for now.
+const JSONB_VERSION: u8 = 1;
+
+fn write_json<W: io::Write>(writer: &mut W, value: &str) -> io::Result<()> {
+    debug_assert!(value.len() < i32::MAX as usize);
+
+    writer.write_i32::<NetworkEndian>(value.len() as i32)?;
+    writer.write_all(value.as_bytes())
+}
+
+fn write_jsonb<W: io::Write>(writer: &mut W, value: &str) -> io::Result<()> {
+    debug_assert!(value.len() < i32::MAX as usize);
+
+    writer.write_i32::<NetworkEndian>(1 + value.len() as i32)?;
+    writer.write_u8(JSONB_VERSION)?;
+    writer.write_all(value.as_bytes())
+}
+
+/// Wrapper for any `Jsonb` implementor, which should be written as a `jsonb` array element.
+///
+/// Array element type is defined by the element Rust type, and `json` is assumed by default
+/// for the types implementing both `Json` and `Jsonb`, so `jsonb[]` elements should be wrapped.
+///
+/// ```edition2018
+/// # use pgcopy::Encoder;
+/// # use pgcopy::types::PgJsonb;
+/// #
+/// let mut encoder = Encoder::new(vec![]);
+/// encoder.write_array(vec![PgJsonb(r#"{"a": 1}"#), PgJsonb("[]")]).unwrap();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct PgJsonb<T>(pub T);
+
+impl<T: Jsonb> Jsonb for PgJsonb<T> {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        self.0.to_writer(writer)
+    }
+}
+
+impl<T: Jsonb> Element for PgJsonb<T> {
+    const OID: u32 = oid::JSONB;
+
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        Jsonb::to_writer(self, writer)
+    }
+}
+
+impl Json for &str {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        write_json(writer, self)
+    }
+}
+
+impl Json for String {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        write_json(writer, self)
+    }
+}
+
+impl Jsonb for &str {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        write_jsonb(writer, self)
+    }
+}
+
+impl Jsonb for String {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        write_jsonb(writer, self)
+    }
+}
+
+#[cfg(feature = "with-serde_json")]
+mod with_serde_json {
+    use super::*;
+
+    use serde::Serialize;
+    use serde_json::Value;
+    use serde_json::value::RawValue;
+
+    /// Wrapper for any [serde](https://docs.rs/serde) serializable value,
+    /// which should be written as a `json` or `jsonb` value.
+    ///
+    /// Value is serialized twice, so its `Serialize` implementation is required to be deterministic,
+    /// producing the same output both times. Otherwise an error is returned,
+    /// and the writer contents should be discarded, as the length prefix is written already.
+    ///
+    /// ```edition2018
+    /// # use std::collections::BTreeMap;
+    /// # use pgcopy::Encoder;
+    /// # use pgcopy::types::PgJson;
+    /// #
+    /// let mut tags = BTreeMap::new();
+    /// tags.insert("env", "production");
+    ///
+    /// let mut encoder = Encoder::new(vec![]);
+    /// encoder.write_jsonb(PgJson(&tags)).unwrap();
+    /// ```
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct PgJson<T>(pub T);
+
+    /// Writer which counts written bytes and discards them.
+    #[derive(Default)]
+    struct Counter(usize);
+
+    impl io::Write for Counter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0 += buf.len();
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    /// Writer which passes through exactly the expected amount of bytes, failing on any excess.
+    struct Limited<'a, W> {
+        inner: &'a mut W,
+        remaining: usize,
+    }
+
+    impl<'a, W: io::Write> io::Write for Limited<'a, W> {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            if buf.len() > self.remaining {
+                return Err(mismatch());
+            }
+            let written = self.inner.write(buf)?;
+            self.remaining -= written;
+
+            Ok(written)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            self.inner.flush()
+        }
+    }
+
+    fn mismatch() -> io::Error {
+        io::Error::new(io::ErrorKind::InvalidData, "JSON value serialized to a different length on the second pass")
+    }
+
+    /// Serializes `value` straight into the `writer`.
+    ///
+    /// Value is serialized twice: first time to calculate the length of the data
+    /// and the second time to actually write it, so no intermediate buffer is required.
+    /// Second pass is checked to produce exactly the same amount of bytes as the first one.
+    fn write_serialized<W, T>(writer: &mut W, value: &T, version: Option<u8>) -> io::Result<()>
+            where W: io::Write, T: Serialize + ?Sized {
+        let mut counter = Counter::default();
+        serde_json::to_writer(&mut counter, value)?;
+
+        let len = counter.0 + version.map_or(0, |_| 1);
+        if len > i32::MAX as usize {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "JSON value is too large"));
+        }
+
+        writer.write_i32::<NetworkEndian>(len as i32)?;
+        if let Some(version) = version {
+            writer.write_u8(version)?;
+        }
+
+        let mut limited = Limited {
+            inner: writer,
+            remaining: counter.0,
+        };
+        serde_json::to_writer(&mut limited, value)?;
+        if limited.remaining != 0 {
+            return Err(mismatch());
+        }
+
+        Ok(())
+    }
+
+    impl<T: Serialize> Json for PgJson<T> {
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            write_serialized(writer, &self.0, None)
+        }
+    }
+
+    impl<T: Serialize> Jsonb for PgJson<T> {
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            write_serialized(writer, &self.0, Some(JSONB_VERSION))
+        }
+    }
+
+    /// `PgJson` elements are written as `json`, wrap them into `PgJsonb` for `jsonb[]` columns.
+    impl<T: Serialize> Element for PgJson<T> {
+        const OID: u32 = oid::JSON;
+
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            Json::to_writer(self, writer)
+        }
+    }
+
+    impl Json for Value {
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            write_serialized(writer, self, None)
+        }
+    }
+
+    impl Json for &Value {
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            write_serialized(writer, *self, None)
+        }
+    }
+
+    impl Jsonb for Value {
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            write_serialized(writer, self, Some(JSONB_VERSION))
+        }
+    }
+
+    impl Jsonb for &Value {
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            write_serialized(writer, *self, Some(JSONB_VERSION))
+        }
+    }
+
+    /// `Value` elements are written as `json`, wrap them into `PgJsonb` for `jsonb[]` columns.
+    impl Element for Value {
+        const OID: u32 = oid::JSON;
+
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            Json::to_writer(self, writer)
+        }
+    }
+
+    impl Json for &RawValue {
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            write_json(writer, self.get())
+        }
+    }
+
+    impl Json for Box<RawValue> {
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            write_json(writer, self.get())
+        }
+    }
+
+    impl Jsonb for &RawValue {
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            write_jsonb(writer, self.get())
+        }
+    }
+
+    impl Jsonb for Box<RawValue> {
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            write_jsonb(writer, self.get())
+        }
+    }
+}
+
+#[cfg(feature = "with-serde_json")]
+pub use self::with_serde_json::PgJson;
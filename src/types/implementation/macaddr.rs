@@ -2,7 +2,7 @@ use std::io;
 
 use byteorder::{WriteBytesExt, NetworkEndian};
 
-use crate::types::MacAddr;
+use crate::types::{oid, Element, MacAddr};
 
 impl MacAddr for [u8; 6] {
     fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
@@ -15,13 +15,21 @@ impl MacAddr for [u8; 6] {
     }
 }
 
+impl Element for [u8; 6] {
+    const OID: u32 = oid::MACADDR;
+
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        MacAddr::to_writer(self, writer)
+    }
+}
+
 #[cfg(feature = "with-eui48")]
 mod with_eui48 {
     use std::io;
 
     use byteorder::{WriteBytesExt, NetworkEndian};
     use eui48::MacAddress;
-    use crate::types::MacAddr;
+    use crate::types::{oid, Element, MacAddr};
 
     impl MacAddr for MacAddress {
         fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
@@ -33,4 +41,12 @@ mod with_eui48 {
             Ok(())
         }
     }
+
+    impl Element for MacAddress {
+        const OID: u32 = oid::MACADDR;
+
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            MacAddr::to_writer(self, writer)
+        }
+    }
 }
@@ -2,7 +2,7 @@ use std::io;
 
 use byteorder::{WriteBytesExt, NetworkEndian};
 
-use crate::types::MacAddr8;
+use crate::types::{oid, Element, MacAddr8};
 
 impl MacAddr8 for [u8; 6] {
     fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
@@ -34,6 +34,14 @@ impl MacAddr8 for [u8; 8] {
     }
 }
 
+impl Element for [u8; 8] {
+    const OID: u32 = oid::MACADDR8;
+
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        MacAddr8::to_writer(self, writer)
+    }
+}
+
 #[cfg(feature = "with-eui48")]
 mod with_eui48 {
     use std::io;
@@ -1,6 +1,66 @@
+use std::io;
+
+use byteorder::{WriteBytesExt, NetworkEndian};
+
+mod out_of_range;
+mod primitive;
+mod option;
+mod numeric;
+mod money;
 mod timestamp;
 mod date;
 mod time;
+mod interval;
+mod enumeration;
 mod uuid;
+mod inet;
+mod geometric;
+mod geometry;
 mod macaddr;
 mod macaddr8;
+mod bit;
+mod text_search;
+mod json;
+mod xml;
+mod element;
+mod array;
+mod composite;
+mod range;
+mod multirange;
+
+pub use self::out_of_range::OutOfRange;
+pub use self::numeric::PgNumeric;
+pub use self::timestamp::PgTimestamp;
+pub use self::date::PgDate;
+pub use self::time::PgTimeTz;
+pub use self::interval::PgInterval;
+pub use self::money::PgMoney;
+pub use self::json::PgJsonb;
+pub use self::array::{PgArray, Dimension};
+pub use self::composite::PgComposite;
+pub use self::range::PgRange;
+pub use self::multirange::PgMultirange;
+pub use self::geometric::{PgPoint, PgLine, PgLineSegment, PgBox, PgPath, PgPolygon, PgCircle};
+pub use self::geometry::{PgCoord, PgGeometry, PgSrid};
+pub use self::bit::PgBitString;
+pub use self::text_search::{PgTsWeight, PgLexeme, PgTsVector, PgTsQuery};
+#[cfg(feature = "with-serde_json")]
+pub use self::json::PgJson;
+#[cfg(feature = "with-quick-xml")]
+pub use self::xml::PgCheckedXml;
+
+/// Writes data produced by `f` prefixed with its length.
+///
+/// Used by the container types, which length is not known until all of their contents are written.
+pub(crate) fn write_length_prefixed<W, F>(writer: &mut W, f: F) -> io::Result<()>
+        where W: io::Write, F: FnOnce(&mut Vec<u8>) -> io::Result<()> {
+    let mut buf = Vec::new();
+    f(&mut buf)?;
+
+    if buf.len() > i32::MAX as usize {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, "value is too large"));
+    }
+
+    writer.write_i32::<NetworkEndian>(buf.len() as i32)?;
+    writer.write_all(&buf)
+}
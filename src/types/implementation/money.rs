@@ -0,0 +1,81 @@
+use std::io;
+
+use byteorder::{WriteBytesExt, NetworkEndian};
+
+use crate::types::{oid, Element, Money};
+
+// https://github.com/postgres/postgres/blob/master/src/backend/utils/adt/cash.c
+
+/// Native `money` value, an amount of the smallest currency units.
+///
+/// Amount of the fractional digits is defined by the server `lc_monetary` setting,
+/// for example, `12.34` is stored as `1234` for the locales with two fractional digits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct PgMoney(pub i64);
+
+impl From<i64> for PgMoney {
+    fn from(units: i64) -> PgMoney {
+        PgMoney(units)
+    }
+}
+
+impl Money for PgMoney {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        writer.write_i32::<NetworkEndian>(8)?;
+        writer.write_i64::<NetworkEndian>(self.0)
+    }
+}
+
+impl Element for PgMoney {
+    const OID: u32 = oid::MONEY;
+
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        Money::to_writer(self, writer)
+    }
+}
+
+#[cfg(feature = "with-rust_decimal")]
+mod with_rust_decimal {
+    use std::convert::TryFrom;
+
+    use super::*;
+
+    use rust_decimal::Decimal;
+
+    fn invalid_input(message: String) -> io::Error {
+        io::Error::new(io::ErrorKind::InvalidInput, message)
+    }
+
+    impl PgMoney {
+        /// Converts decimal value into the `money` value with `scale` fractional digits,
+        /// which should match the server `lc_monetary` setting.
+        ///
+        /// Values with more significant fractional digits than `scale` are rejected instead of being truncated.
+        ///
+        /// ```edition2018
+        /// use pgcopy::types::PgMoney;
+        /// use rust_decimal::Decimal;
+        ///
+        /// assert_eq!(PgMoney::from_decimal(Decimal::new(1234, 2), 2).unwrap(), PgMoney(1234));
+        /// assert!(PgMoney::from_decimal(Decimal::new(1234, 3), 2).is_err());
+        /// ```
+        pub fn from_decimal(value: Decimal, scale: u32) -> io::Result<PgMoney> {
+            let overflow = || invalid_input(format!("value {} is out of range for type money", value));
+
+            let mantissa = value.mantissa();
+            let units = if value.scale() > scale {
+                let divisor = 10i128.checked_pow(value.scale() - scale).ok_or_else(overflow)?;
+                if mantissa % divisor != 0 {
+                    return Err(invalid_input(format!("value {} has more than {} fractional digits", value, scale)));
+                }
+                mantissa / divisor
+            } else {
+                10i128.checked_pow(scale - value.scale())
+                    .and_then(|multiplier| mantissa.checked_mul(multiplier))
+                    .ok_or_else(overflow)?
+            };
+
+            i64::try_from(units).map(PgMoney).map_err(|_| overflow())
+        }
+    }
+}
@@ -0,0 +1,223 @@
+use std::cmp::Ordering;
+use std::io;
+use std::iter::FromIterator;
+use std::ops::Bound;
+
+use byteorder::{WriteBytesExt, NetworkEndian};
+
+use super::write_length_prefixed;
+use super::range::finite;
+use crate::types::{oid, Element, Multirange, PgRange, Range};
+
+// https://github.com/postgres/postgres/blob/master/src/backend/utils/adt/multirangetypes.c
+
+/// Native multirange value.
+///
+/// Any iterator of ranges can be collected into it:
+///
+/// ```edition2018
+/// # use pgcopy::Encoder;
+/// # use pgcopy::types::PgMultirange;
+/// #
+/// let mut encoder = Encoder::new(vec![]);
+///
+/// // '{[1,5),[8,10)}'::int4multirange
+/// let multirange = vec![3..5, 8..10, 1..4].into_iter().collect::<PgMultirange<i32>>();
+/// encoder.write_multirange(multirange.normalized()).unwrap();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct PgMultirange<T> {
+    ranges: Vec<PgRange<T>>,
+}
+
+impl<T> PgMultirange<T> {
+    /// Creates new multirange without any ranges.
+    pub fn new() -> PgMultirange<T> {
+        PgMultirange {
+            ranges: Vec::new(),
+        }
+    }
+
+    /// Appends range to the multirange.
+    pub fn push<R: Into<PgRange<T>>>(&mut self, range: R) {
+        self.ranges.push(range.into());
+    }
+
+    /// Returns ranges contained in this multirange.
+    pub fn ranges(&self) -> &[PgRange<T>] {
+        &self.ranges
+    }
+}
+
+impl<T: Element + Ord> PgMultirange<T> {
+    /// Normalizes multirange the same way as the server does it.
+    ///
+    /// Empty ranges are removed, remaining ones are sorted by their lower bounds
+    /// and overlapping or adjacent ranges are merged together.
+    /// `NULL` bound values, like `None`, are replaced with an infinite bounds, as they are written so.
+    ///
+    /// Note that discrete ranges, like `int4range`, are not canonicalized,
+    /// so `[1,2]` and `[3,4]` ranges are kept separate, while server merges them into a `[1,5)` range.
+    pub fn normalized(self) -> PgMultirange<T> {
+        let mut ranges = self.ranges.into_iter()
+            .filter_map(|range| match range {
+                PgRange::Bounds { lower, upper } => Some((finite(lower), finite(upper))),
+                PgRange::Empty => None,
+            })
+            .filter(|(lower, upper)| !is_empty(lower, upper))
+            .collect::<Vec<_>>();
+        ranges.sort_by(|left, right| cmp_lower(&left.0, &right.0));
+
+        let mut normalized: Vec<(Bound<T>, Bound<T>)> = Vec::with_capacity(ranges.len());
+        for (lower, upper) in ranges {
+            match normalized.last_mut() {
+                Some(last) if touches(&last.1, &lower) => {
+                    if cmp_upper(&upper, &last.1) == Ordering::Greater {
+                        last.1 = upper;
+                    }
+                },
+                _ => normalized.push((lower, upper)),
+            }
+        }
+
+        normalized.into_iter().map(|(lower, upper)| PgRange::new(lower, upper)).collect()
+    }
+}
+
+impl<T, R: Into<PgRange<T>>> FromIterator<R> for PgMultirange<T> {
+    fn from_iter<I: IntoIterator<Item = R>>(iter: I) -> PgMultirange<T> {
+        PgMultirange {
+            ranges: iter.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl<T> From<Vec<PgRange<T>>> for PgMultirange<T> {
+    fn from(ranges: Vec<PgRange<T>>) -> PgMultirange<T> {
+        PgMultirange {
+            ranges,
+        }
+    }
+}
+
+fn value<T>(bound: &Bound<T>) -> Option<&T> {
+    match bound {
+        Bound::Included(value) | Bound::Excluded(value) => Some(value),
+        Bound::Unbounded => None,
+    }
+}
+
+/// Compares lower bounds, infinite bound goes first and inclusive bound goes before an exclusive one.
+fn cmp_lower<T: Ord>(left: &Bound<T>, right: &Bound<T>) -> Ordering {
+    match (left, right) {
+        (Bound::Unbounded, Bound::Unbounded) => Ordering::Equal,
+        (Bound::Unbounded, _) => Ordering::Less,
+        (_, Bound::Unbounded) => Ordering::Greater,
+        (Bound::Included(l), Bound::Excluded(r)) if l == r => Ordering::Less,
+        (Bound::Excluded(l), Bound::Included(r)) if l == r => Ordering::Greater,
+        _ => value(left).cmp(&value(right)),
+    }
+}
+
+/// Compares upper bounds, infinite bound goes last and inclusive bound goes after an exclusive one.
+fn cmp_upper<T: Ord>(left: &Bound<T>, right: &Bound<T>) -> Ordering {
+    match (left, right) {
+        (Bound::Unbounded, Bound::Unbounded) => Ordering::Equal,
+        (Bound::Unbounded, _) => Ordering::Greater,
+        (_, Bound::Unbounded) => Ordering::Less,
+        (Bound::Included(l), Bound::Excluded(r)) if l == r => Ordering::Greater,
+        (Bound::Excluded(l), Bound::Included(r)) if l == r => Ordering::Less,
+        _ => value(left).cmp(&value(right)),
+    }
+}
+
+fn is_empty<T: Ord>(lower: &Bound<T>, upper: &Bound<T>) -> bool {
+    match (lower, upper) {
+        (Bound::Included(l), Bound::Included(u)) => l > u,
+        (Bound::Included(l), Bound::Excluded(u))
+        | (Bound::Excluded(l), Bound::Included(u))
+        | (Bound::Excluded(l), Bound::Excluded(u)) => l >= u,
+        _ => false,
+    }
+}
+
+/// Returns `true` if range with the `lower` bound overlaps or is adjacent to the range with `upper` bound,
+/// assuming that it does not start before that range.
+fn touches<T: Ord>(upper: &Bound<T>, lower: &Bound<T>) -> bool {
+    match (upper, lower) {
+        (Bound::Unbounded, _) | (_, Bound::Unbounded) => true,
+        (Bound::Excluded(u), Bound::Excluded(l)) => l < u,
+        (Bound::Included(u), Bound::Included(l))
+        | (Bound::Included(u), Bound::Excluded(l))
+        | (Bound::Excluded(u), Bound::Included(l)) => l <= u,
+    }
+}
+
+fn write_multirange<'a, W, R, I>(writer: &mut W, ranges: I) -> io::Result<()>
+        where W: io::Write, R: Range + 'a, I: ExactSizeIterator<Item = &'a R> {
+    if ranges.len() > i32::MAX as usize {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, "too many ranges in multirange"));
+    }
+
+    write_length_prefixed(writer, |buf| {
+        buf.write_i32::<NetworkEndian>(ranges.len() as i32)?;
+        for range in ranges {
+            range.to_writer(buf)?;
+        }
+
+        Ok(())
+    })
+}
+
+impl<T: Element> Multirange for PgMultirange<T> {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        write_multirange(writer, self.ranges.iter())
+    }
+}
+
+impl<T: Element> Multirange for &PgMultirange<T> {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        Multirange::to_writer(*self, writer)
+    }
+}
+
+/// Returns OID of the built-in multirange type over the `subtype`, or `anymultirange` if there is none.
+const fn multirange_oid(subtype: u32) -> u32 {
+    match subtype {
+        oid::INT4 => oid::INT4MULTIRANGE,
+        oid::INT8 => oid::INT8MULTIRANGE,
+        oid::NUMERIC => oid::NUMMULTIRANGE,
+        oid::TIMESTAMP => oid::TSMULTIRANGE,
+        oid::TIMESTAMPTZ => oid::TSTZMULTIRANGE,
+        oid::DATE => oid::DATEMULTIRANGE,
+        _ => oid::ANYMULTIRANGE,
+    }
+}
+
+/// Multiranges are elements of the built-in multirange type over the bound values type,
+/// actual type OID should be provided for the user-defined multirange types.
+impl<T: Element> Element for PgMultirange<T> {
+    const OID: u32 = multirange_oid(T::OID);
+
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        Multirange::to_writer(self, writer)
+    }
+}
+
+impl<R: Range> Multirange for &[R] {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        write_multirange(writer, self.iter())
+    }
+}
+
+impl<R: Range, const N: usize> Multirange for [R; N] {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        write_multirange(writer, self.iter())
+    }
+}
+
+impl<R: Range> Multirange for Vec<R> {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        write_multirange(writer, self.iter())
+    }
+}
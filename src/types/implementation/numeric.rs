@@ -2,44 +2,262 @@ use std::io;
 
 use byteorder::{WriteBytesExt, NetworkEndian};
 
-use crate::types;
+use crate::types::{oid, Element, Numeric};
 
-impl types::Type for i16 {
-    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
-        writer.write_i32::<NetworkEndian>(2)?;
-        writer.write_i16::<NetworkEndian>(*self)
+// https://github.com/postgres/postgres/blob/master/src/backend/utils/adt/numeric.c
+
+const NUMERIC_POS: u16 = 0x0000;
+const NUMERIC_NEG: u16 = 0x4000;
+const NUMERIC_NAN: u16 = 0xC000;
+const NUMERIC_PINF: u16 = 0xD000;
+const NUMERIC_NINF: u16 = 0xF000;
+
+/// Maximum display scale accepted by the server.
+const NUMERIC_DSCALE_MAX: i64 = 0x3FFF;
+
+/// Decimal digits amount in one base-10000 digit.
+const DEC_DIGITS: i64 = 4;
+
+fn invalid_input(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, message)
+}
+
+/// Writes one of the `NaN`, `Infinity` or `-Infinity` values.
+fn write_special<W: io::Write>(writer: &mut W, sign: u16) -> io::Result<()> {
+    writer.write_i32::<NetworkEndian>(8)?;
+    writer.write_i16::<NetworkEndian>(0)?; // ndigits
+    writer.write_i16::<NetworkEndian>(0)?; // weight
+    writer.write_u16::<NetworkEndian>(sign)?;
+    writer.write_u16::<NetworkEndian>(0) // dscale
+}
+
+/// Writes finite value, which equals to the `digits` decimal ASCII digits multiplied by `10^-scale`.
+///
+/// Negative `scale` is allowed and means that the value has trailing zeroes omitted from `digits`.
+pub(crate) fn write_decimal<W: io::Write>(writer: &mut W, negative: bool, digits: &[u8], scale: i64) -> io::Result<()> {
+    let dscale = scale.max(0);
+    if dscale > NUMERIC_DSCALE_MAX {
+        return Err(invalid_input(format!("numeric display scale {} is out of range", dscale)));
+    }
+
+    // Exponents (as a powers of ten) of the most and the least significant decimal digits
+    let first = digits.len() as i64 - 1 - scale;
+    let last = -scale;
+
+    // Base-10000 digits, starting from the one with `weight` exponent
+    let weight = first.div_euclid(DEC_DIGITS);
+    let groups_len = (weight - last.div_euclid(DEC_DIGITS) + 1).max(0) as usize;
+    let mut groups = vec![0i16; groups_len];
+    for (idx, digit) in digits.iter().enumerate() {
+        if !digit.is_ascii_digit() {
+            return Err(invalid_input(format!("invalid decimal digit {:?}", *digit as char)));
+        }
+        let exponent = first - idx as i64;
+        let group = (weight - exponent.div_euclid(DEC_DIGITS)) as usize;
+        groups[group] += i16::from(digit - b'0') * 10i16.pow(exponent.rem_euclid(DEC_DIGITS) as u32);
+    }
+
+    let leading = groups.iter().take_while(|group| **group == 0).count();
+    let trailing = groups[leading..].iter().rev().take_while(|group| **group == 0).count();
+    let groups = &groups[leading..groups.len() - trailing];
+
+    let (weight, sign) = if groups.is_empty() {
+        (0, NUMERIC_POS)
+    } else if negative {
+        (weight - leading as i64, NUMERIC_NEG)
+    } else {
+        (weight - leading as i64, NUMERIC_POS)
+    };
+    if weight < i64::from(i16::MIN) || weight > i64::from(i16::MAX) || groups.len() > i16::MAX as usize {
+        return Err(invalid_input("value overflows numeric format".to_string()));
+    }
+
+    writer.write_i32::<NetworkEndian>(8 + 2 * groups.len() as i32)?;
+    writer.write_i16::<NetworkEndian>(groups.len() as i16)?;
+    writer.write_i16::<NetworkEndian>(weight as i16)?;
+    writer.write_u16::<NetworkEndian>(sign)?;
+    writer.write_u16::<NetworkEndian>(dscale as u16)?;
+    for group in groups {
+        writer.write_i16::<NetworkEndian>(*group)?;
+    }
+
+    Ok(())
+}
+
+/// Writes value from its text representation, as accepted by PostgreSQL `numeric` input function.
+fn write_str<W: io::Write>(writer: &mut W, value: &str) -> io::Result<()> {
+    let invalid = || invalid_input(format!("invalid input syntax for type numeric: {:?}", value));
+
+    let trimmed = value.trim();
+    let (negative, unsigned) = if let Some(rest) = trimmed.strip_prefix('-') {
+        (true, rest)
+    } else {
+        (false, trimmed.strip_prefix('+').unwrap_or(trimmed))
+    };
+
+    if unsigned.eq_ignore_ascii_case("nan") {
+        if unsigned.len() != trimmed.len() {
+            return Err(invalid());
+        }
+        return write_special(writer, NUMERIC_NAN);
+    }
+    if unsigned.eq_ignore_ascii_case("infinity") || unsigned.eq_ignore_ascii_case("inf") {
+        return write_special(writer, if negative { NUMERIC_NINF } else { NUMERIC_PINF });
+    }
+
+    let (mantissa, exponent) = match unsigned.find(['e', 'E']) {
+        Some(idx) => {
+            let exponent = unsigned[idx + 1..].parse::<i32>().map_err(|_| invalid())?;
+            (&unsigned[..idx], i64::from(exponent))
+        },
+        None => (unsigned, 0),
+    };
+    let (integer, fraction) = match mantissa.find('.') {
+        Some(idx) => (&mantissa[..idx], &mantissa[idx + 1..]),
+        None => (mantissa, ""),
+    };
+    if integer.is_empty() && fraction.is_empty() {
+        return Err(invalid());
     }
+    if !integer.bytes().chain(fraction.bytes()).all(|byte| byte.is_ascii_digit()) {
+        return Err(invalid());
+    }
+
+    let digits = [integer.as_bytes(), fraction.as_bytes()].concat();
+    write_decimal(writer, negative, &digits, fraction.len() as i64 - exponent)
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {
+        $(
+            impl Numeric for $t {
+                fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+                    let repr = self.to_string();
+                    match repr.strip_prefix('-') {
+                        Some(digits) => write_decimal(writer, true, digits.as_bytes(), 0),
+                        None => write_decimal(writer, false, repr.as_bytes(), 0),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_float {
+    ($($t:ty),*) => {
+        $(
+            impl Numeric for $t {
+                fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+                    if self.is_nan() {
+                        write_special(writer, NUMERIC_NAN)
+                    } else if self.is_infinite() && self.is_sign_positive() {
+                        write_special(writer, NUMERIC_PINF)
+                    } else if self.is_infinite() {
+                        write_special(writer, NUMERIC_NINF)
+                    } else {
+                        // `Display` implementation produces the shortest representation
+                        // which can be parsed back to the same value and never uses an exponent.
+                        write_str(writer, &self.to_string())
+                    }
+                }
+            }
+        )*
+    };
 }
-impl types::SmallInt for i16 {}
 
-impl types::Type for i32 {
+impl_float!(f32, f64);
+
+impl Numeric for &str {
     fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
-        writer.write_i32::<NetworkEndian>(4)?;
-        writer.write_i32::<NetworkEndian>(*self)
+        write_str(writer, self)
     }
 }
-impl types::Integer for i32 {}
 
-impl types::Type for i64 {
+impl Numeric for String {
     fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
-        writer.write_i32::<NetworkEndian>(8)?;
-        writer.write_i64::<NetworkEndian>(*self)
+        write_str(writer, self)
     }
 }
-impl types::BigInt for i64 {}
 
-impl types::Type for f32 {
+/// Wrapper for any `Numeric` implementor, which should be written as a `numeric` array element or range bound.
+///
+/// Array element type is defined by the element Rust type, and integers, floats and strings
+/// are written as the `int`, `float` and `text` elements by default, so `numeric[]` elements should be wrapped.
+///
+/// ```edition2018
+/// # use std::ops::Bound;
+/// # use pgcopy::Encoder;
+/// # use pgcopy::types::{PgNumeric, PgRange};
+/// #
+/// let mut encoder = Encoder::new(vec![]);
+/// // '{1,2.5}'::numeric[]
+/// encoder.write_array(vec![PgNumeric("1"), PgNumeric("2.5")]).unwrap();
+/// // '{"[1,10)"}'::numrange[]
+/// encoder.write_array(vec![PgRange::from(PgNumeric(1)..PgNumeric(10))]).unwrap();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct PgNumeric<T>(pub T);
+
+impl<T: Numeric> Numeric for PgNumeric<T> {
     fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
-        writer.write_i32::<NetworkEndian>(4)?;
-        writer.write_f32::<NetworkEndian>(*self)
+        self.0.to_writer(writer)
     }
 }
-impl types::Real for f32 {}
 
-impl types::Type for f64 {
+impl<T: Numeric> Element for PgNumeric<T> {
+    const OID: u32 = oid::NUMERIC;
+
     fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
-        writer.write_i32::<NetworkEndian>(8)?;
-        writer.write_f64::<NetworkEndian>(*self)
+        Numeric::to_writer(self, writer)
+    }
+}
+
+#[cfg(feature = "with-rust_decimal")]
+mod with_rust_decimal {
+    use super::*;
+
+    use rust_decimal::Decimal;
+
+    impl Numeric for Decimal {
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            let digits = self.mantissa().unsigned_abs().to_string();
+            write_decimal(writer, self.is_sign_negative(), digits.as_bytes(), i64::from(self.scale()))
+        }
+    }
+
+    impl Element for Decimal {
+        const OID: u32 = oid::NUMERIC;
+
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            Numeric::to_writer(self, writer)
+        }
+    }
+}
+
+#[cfg(feature = "with-bigdecimal")]
+mod with_bigdecimal {
+    use super::*;
+
+    use bigdecimal::BigDecimal;
+
+    impl Numeric for BigDecimal {
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            let (value, scale) = self.as_bigint_and_exponent();
+            let repr = value.to_string();
+            match repr.strip_prefix('-') {
+                Some(digits) => write_decimal(writer, true, digits.as_bytes(), scale),
+                None => write_decimal(writer, false, repr.as_bytes(), scale),
+            }
+        }
+    }
+
+    impl Element for BigDecimal {
+        const OID: u32 = oid::NUMERIC;
+
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            Numeric::to_writer(self, writer)
+        }
     }
 }
-impl types::Double for f64 {}
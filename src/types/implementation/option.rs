@@ -0,0 +1,31 @@
+use std::io;
+
+use byteorder::{WriteBytesExt, NetworkEndian};
+
+use crate::types::*;
+
+/// Implements column type traits for `Option` of their implementors, `None` value is written as `NULL`.
+macro_rules! impl_option {
+    ($($trait:ident),* $(,)?) => {
+        $(
+            impl<T: $trait> $trait for Option<T> {
+                fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+                    match self {
+                        Some(value) => value.to_writer(writer),
+                        None => writer.write_i32::<NetworkEndian>(-1),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_option!(
+    Numeric, Money,
+    Timestamp, TimestampWithTimeZone, Date, Time, TimeWithTimeZone, Interval,
+    Point, Line, LineSegment, Rectangle, Path, Polygon, Circle, Geometry,
+    Cidr, Inet, Enum, MacAddr, MacAddr8,
+    Bit, VarBit, TsVector, TsQuery,
+    Uuid, Xml, Json, Jsonb,
+    Array, Composite, Range, Multirange,
+);
@@ -0,0 +1,53 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+/// Error for values outside of the range supported by the PostgreSQL type.
+///
+/// It is returned wrapped into the `io::Error` of the `InvalidInput` kind:
+///
+/// ```edition2018
+/// # #[cfg(feature = "with-chrono")]
+/// # {
+/// use chrono::NaiveDate;
+/// use pgcopy::types::OutOfRange;
+///
+/// let mut encoder = pgcopy::Encoder::new(vec![]);
+/// let err = encoder.write_date(NaiveDate::from_ymd_opt(-5000, 1, 1).unwrap()).unwrap_err();
+///
+/// let out_of_range = err.get_ref().and_then(|inner| inner.downcast_ref::<OutOfRange>());
+/// assert_eq!(out_of_range, Some(&OutOfRange::new("date")));
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OutOfRange {
+    type_name: &'static str,
+}
+
+impl OutOfRange {
+    /// Creates new error for the PostgreSQL type named `type_name`.
+    pub fn new(type_name: &'static str) -> OutOfRange {
+        OutOfRange {
+            type_name,
+        }
+    }
+
+    /// PostgreSQL type name, which range was exceeded.
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+}
+
+impl fmt::Display for OutOfRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} out of range", self.type_name)
+    }
+}
+
+impl Error for OutOfRange {}
+
+impl From<OutOfRange> for io::Error {
+    fn from(err: OutOfRange) -> io::Error {
+        io::Error::new(io::ErrorKind::InvalidInput, err)
+    }
+}
@@ -0,0 +1,152 @@
+use std::borrow::Cow;
+use std::io;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use byteorder::{WriteBytesExt, NetworkEndian};
+
+use crate::types::{oid, Element, SmallInt, Int, BigInt, Real, Double, Bool, Text, Bytea};
+
+fn write_null<W: io::Write>(writer: &mut W) -> io::Result<()> {
+    writer.write_i32::<NetworkEndian>(-1)
+}
+
+/// Implements trait for the primitive type and its `Option`, `None` value is written as `NULL`,
+/// and makes the type an array element of the `$oid` type.
+///
+/// `Option` is implemented for the concrete type only, so `None` literal does not need any type annotations.
+macro_rules! impl_primitive {
+    ($trait:ident, $ty:ty, $oid:expr, |$value:ident, $writer:ident| $body:expr) => {
+        impl $trait for $ty {
+            fn to_writer<W: io::Write>(&self, $writer: &mut W) -> io::Result<()> {
+                let $value = *self;
+                $body
+            }
+        }
+
+        impl $trait for Option<$ty> {
+            fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+                match self {
+                    Some(value) => $trait::to_writer(value, writer),
+                    None => write_null(writer),
+                }
+            }
+        }
+
+        impl Element for $ty {
+            const OID: u32 = $oid;
+
+            fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+                $trait::to_writer(self, writer)
+            }
+        }
+    };
+}
+
+impl_primitive!(SmallInt, i16, oid::INT2, |value, writer| {
+    writer.write_i32::<NetworkEndian>(2)?;
+    writer.write_i16::<NetworkEndian>(value)
+});
+
+impl_primitive!(Int, i32, oid::INT4, |value, writer| {
+    writer.write_i32::<NetworkEndian>(4)?;
+    writer.write_i32::<NetworkEndian>(value)
+});
+
+impl_primitive!(BigInt, i64, oid::INT8, |value, writer| {
+    writer.write_i32::<NetworkEndian>(8)?;
+    writer.write_i64::<NetworkEndian>(value)
+});
+
+impl_primitive!(Real, f32, oid::FLOAT4, |value, writer| {
+    writer.write_i32::<NetworkEndian>(4)?;
+    writer.write_f32::<NetworkEndian>(value)
+});
+
+impl_primitive!(Double, f64, oid::FLOAT8, |value, writer| {
+    writer.write_i32::<NetworkEndian>(8)?;
+    writer.write_f64::<NetworkEndian>(value)
+});
+
+impl_primitive!(Bool, bool, oid::BOOL, |value, writer| {
+    writer.write_i32::<NetworkEndian>(1)?;
+    writer.write_i8(value as i8)
+});
+
+fn write_bytes<W: io::Write>(writer: &mut W, bytes: &[u8]) -> io::Result<()> {
+    debug_assert!(bytes.len() < i32::MAX as usize);
+
+    writer.write_i32::<NetworkEndian>(bytes.len() as i32)?;
+    writer.write_all(bytes)
+}
+
+/// Implements trait for the owned and borrowed values dereferencing to the `$target`.
+macro_rules! impl_unsized {
+    ($trait:ident, $target:ty, [$($ty:ty),*]) => {
+        $(
+            impl $trait for $ty {
+                fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+                    let value: &$target = self;
+                    write_bytes(writer, value.as_ref())
+                }
+            }
+        )*
+
+        impl<T: $trait + ?Sized> $trait for &T {
+            fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+                (**self).to_writer(writer)
+            }
+        }
+
+        impl<T: $trait> $trait for Option<T> {
+            fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+                match self {
+                    Some(value) => value.to_writer(writer),
+                    None => write_null(writer),
+                }
+            }
+        }
+    };
+}
+
+impl_unsized!(Text, str, [str, String, Box<str>, Cow<'_, str>, Rc<str>, Arc<str>]);
+
+impl_unsized!(Bytea, [u8], [[u8], Vec<u8>, Box<[u8]>, Cow<'_, [u8]>, Rc<[u8]>, Arc<[u8]>]);
+
+impl<const N: usize> Bytea for [u8; N] {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        write_bytes(writer, self)
+    }
+}
+
+/// Text is written as its UTF-8 bytes.
+impl Bytea for str {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        write_bytes(writer, self.as_bytes())
+    }
+}
+
+impl Bytea for String {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        write_bytes(writer, self.as_bytes())
+    }
+}
+
+/// Implements `Element` for the types written by the `$trait` as the `$oid` type.
+macro_rules! impl_element {
+    ($trait:ident, $oid:expr, [$($ty:ty),*]) => {
+        $(
+            impl Element for $ty {
+                const OID: u32 = $oid;
+
+                fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+                    $trait::to_writer(self, writer)
+                }
+            }
+        )*
+    };
+}
+
+impl_element!(Text, oid::TEXT, [str, String, Box<str>, Cow<'_, str>, Rc<str>, Arc<str>]);
+
+impl_element!(Bytea, oid::BYTEA, [[u8], Vec<u8>, Box<[u8]>, Cow<'_, [u8]>, Rc<[u8]>, Arc<[u8]>]);
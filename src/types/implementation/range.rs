@@ -0,0 +1,206 @@
+use std::io;
+use std::ops::{self, Bound};
+
+use byteorder::WriteBytesExt;
+
+use super::write_length_prefixed;
+use crate::types::{oid, Element, Range};
+
+// https://github.com/postgres/postgres/blob/master/src/backend/utils/adt/rangetypes.c
+
+const RANGE_EMPTY: u8 = 0x01;
+const RANGE_LB_INC: u8 = 0x02;
+const RANGE_UB_INC: u8 = 0x04;
+const RANGE_LB_INF: u8 = 0x08;
+const RANGE_UB_INF: u8 = 0x10;
+
+/// Native range value with explicit bounds.
+///
+/// `NULL` bound values, like `None`, are written as an infinite bounds,
+/// same as the server treats `NULL` arguments of the range constructor functions.
+///
+/// ```edition2018
+/// # use std::ops::Bound;
+/// # use pgcopy::Encoder;
+/// # use pgcopy::types::PgRange;
+/// #
+/// let mut encoder = Encoder::new(vec![]);
+///
+/// // '(1,10]'::int4range
+/// encoder.write_range(PgRange::new(Bound::Excluded(1), Bound::Included(10))).unwrap();
+/// // 'empty'::int4range
+/// encoder.write_range(PgRange::<i32>::Empty).unwrap();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PgRange<T> {
+    /// Range containing no points at all.
+    Empty,
+    /// Range with the lower and upper bounds.
+    Bounds {
+        lower: Bound<T>,
+        upper: Bound<T>,
+    },
+}
+
+impl<T> PgRange<T> {
+    /// Creates new range with the lower and upper bounds.
+    pub fn new(lower: Bound<T>, upper: Bound<T>) -> PgRange<T> {
+        PgRange::Bounds {
+            lower,
+            upper,
+        }
+    }
+}
+
+/// Writes range with the provided bounds, or an empty range if `bounds` are `None`.
+pub(crate) fn write_range<W, T>(writer: &mut W, bounds: Option<(Bound<&T>, Bound<&T>)>) -> io::Result<()>
+        where W: io::Write, T: Element {
+    let (lower, upper) = match bounds {
+        Some((lower, upper)) => (finite(lower), finite(upper)),
+        None => return write_length_prefixed(writer, |buf| buf.write_u8(RANGE_EMPTY)),
+    };
+
+    let mut flags = 0;
+    match lower {
+        Bound::Included(_) => flags |= RANGE_LB_INC,
+        Bound::Excluded(_) => {},
+        Bound::Unbounded => flags |= RANGE_LB_INF,
+    }
+    match upper {
+        Bound::Included(_) => flags |= RANGE_UB_INC,
+        Bound::Excluded(_) => {},
+        Bound::Unbounded => flags |= RANGE_UB_INF,
+    }
+
+    write_length_prefixed(writer, |buf| {
+        buf.write_u8(flags)?;
+        if let Bound::Included(value) | Bound::Excluded(value) = lower {
+            value.to_writer(buf)?;
+        }
+        if let Bound::Included(value) | Bound::Excluded(value) = upper {
+            value.to_writer(buf)?;
+        }
+
+        Ok(())
+    })
+}
+
+/// Replaces `NULL` bound with an infinite one.
+pub(crate) fn finite<T: Element>(bound: Bound<T>) -> Bound<T> {
+    match bound {
+        Bound::Included(value) | Bound::Excluded(value) if value.is_null() => Bound::Unbounded,
+        bound => bound,
+    }
+}
+
+fn as_ref<T>(bound: &Bound<T>) -> Bound<&T> {
+    match bound {
+        Bound::Included(value) => Bound::Included(value),
+        Bound::Excluded(value) => Bound::Excluded(value),
+        Bound::Unbounded => Bound::Unbounded,
+    }
+}
+
+impl<T: Element> Range for PgRange<T> {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        match self {
+            PgRange::Empty => write_range::<_, T>(writer, None),
+            PgRange::Bounds { lower, upper } => write_range(writer, Some((as_ref(lower), as_ref(upper)))),
+        }
+    }
+}
+
+impl<T: Element> Range for &PgRange<T> {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        Range::to_writer(*self, writer)
+    }
+}
+
+/// Returns OID of the built-in range type over the `subtype`, or `anyrange` if there is none.
+const fn range_oid(subtype: u32) -> u32 {
+    match subtype {
+        oid::INT4 => oid::INT4RANGE,
+        oid::INT8 => oid::INT8RANGE,
+        oid::NUMERIC => oid::NUMRANGE,
+        oid::TIMESTAMP => oid::TSRANGE,
+        oid::TIMESTAMPTZ => oid::TSTZRANGE,
+        oid::DATE => oid::DATERANGE,
+        _ => oid::ANYRANGE,
+    }
+}
+
+/// Ranges are elements of the built-in range type over the bound values type,
+/// actual type OID should be provided for the user-defined range types.
+impl<T: Element> Element for PgRange<T> {
+    const OID: u32 = range_oid(T::OID);
+
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        Range::to_writer(self, writer)
+    }
+}
+
+/// `[start,end)` range.
+impl<T: Element> Range for ops::Range<T> {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        write_range(writer, Some((Bound::Included(&self.start), Bound::Excluded(&self.end))))
+    }
+}
+
+/// `[start,end]` range.
+impl<T: Element> Range for ops::RangeInclusive<T> {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        write_range(writer, Some((Bound::Included(self.start()), Bound::Included(self.end()))))
+    }
+}
+
+/// `[start,)` range.
+impl<T: Element> Range for ops::RangeFrom<T> {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        write_range(writer, Some((Bound::Included(&self.start), Bound::Unbounded)))
+    }
+}
+
+/// `(,end)` range.
+impl<T: Element> Range for ops::RangeTo<T> {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        write_range(writer, Some((Bound::Unbounded, Bound::Excluded(&self.end))))
+    }
+}
+
+/// `(,end]` range.
+impl<T: Element> Range for ops::RangeToInclusive<T> {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        write_range(writer, Some((Bound::Unbounded, Bound::Included(&self.end))))
+    }
+}
+
+impl<T> From<ops::Range<T>> for PgRange<T> {
+    fn from(range: ops::Range<T>) -> PgRange<T> {
+        PgRange::new(Bound::Included(range.start), Bound::Excluded(range.end))
+    }
+}
+
+impl<T> From<ops::RangeInclusive<T>> for PgRange<T> {
+    fn from(range: ops::RangeInclusive<T>) -> PgRange<T> {
+        let (start, end) = range.into_inner();
+        PgRange::new(Bound::Included(start), Bound::Included(end))
+    }
+}
+
+impl<T> From<ops::RangeFrom<T>> for PgRange<T> {
+    fn from(range: ops::RangeFrom<T>) -> PgRange<T> {
+        PgRange::new(Bound::Included(range.start), Bound::Unbounded)
+    }
+}
+
+impl<T> From<ops::RangeTo<T>> for PgRange<T> {
+    fn from(range: ops::RangeTo<T>) -> PgRange<T> {
+        PgRange::new(Bound::Unbounded, Bound::Excluded(range.end))
+    }
+}
+
+impl<T> From<ops::RangeToInclusive<T>> for PgRange<T> {
+    fn from(range: ops::RangeToInclusive<T>) -> PgRange<T> {
+        PgRange::new(Bound::Unbounded, Bound::Included(range.end))
+    }
+}
@@ -0,0 +1,328 @@
+use std::io;
+
+use byteorder::{WriteBytesExt, NetworkEndian};
+
+use crate::types::{oid, Element, TsVector, TsQuery};
+use super::write_length_prefixed;
+
+// https://github.com/postgres/postgres/blob/master/src/backend/utils/adt/tsvector.c
+// https://github.com/postgres/postgres/blob/master/src/backend/utils/adt/tsquery.c
+
+/// Maximum lexeme length in bytes.
+const MAXSTRLEN: usize = (1 << 11) - 1;
+/// Maximum lexeme position.
+const MAXENTRYPOS: u16 = (1 << 14) - 1;
+/// Maximum amount of positions per lexeme.
+const MAXNUMPOS: usize = 256;
+
+const QI_VAL: u8 = 1;
+const QI_OPR: u8 = 2;
+
+const OP_NOT: u8 = 1;
+const OP_AND: u8 = 2;
+const OP_OR: u8 = 3;
+const OP_PHRASE: u8 = 4;
+
+/// Lexeme position weight, `A` is the highest one and `D` is the default one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum PgTsWeight {
+    A,
+    B,
+    C,
+    #[default]
+    D,
+}
+
+impl PgTsWeight {
+    /// Weight value as stored in the two highest bits of the `tsvector` lexeme position.
+    fn index(self) -> u16 {
+        match self {
+            PgTsWeight::A => 3,
+            PgTsWeight::B => 2,
+            PgTsWeight::C => 1,
+            PgTsWeight::D => 0,
+        }
+    }
+}
+
+/// `tsvector` lexeme with its positions in the document.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct PgLexeme {
+    pub word: String,
+    pub positions: Vec<(u16, PgTsWeight)>,
+}
+
+impl PgLexeme {
+    /// Creates new lexeme without positions.
+    pub fn new<T: Into<String>>(word: T) -> PgLexeme {
+        PgLexeme {
+            word: word.into(),
+            positions: Vec::new(),
+        }
+    }
+
+    /// Adds position to the lexeme.
+    ///
+    /// Positions are required to be added in a strictly increasing order and to be in `1..=16383` range.
+    pub fn position(mut self, position: u16, weight: PgTsWeight) -> PgLexeme {
+        self.positions.push((position, weight));
+        self
+    }
+}
+
+/// Native `tsvector` value, a list of the lexemes.
+///
+/// ```edition2018
+/// use pgcopy::types::{PgLexeme, PgTsVector, PgTsWeight};
+///
+/// // 'cat':3A,5 'fat':1
+/// let vector = PgTsVector::new(vec![
+///     PgLexeme::new("cat").position(3, PgTsWeight::A).position(5, PgTsWeight::D),
+///     PgLexeme::new("fat").position(1, PgTsWeight::D),
+/// ]);
+///
+/// let mut encoder = pgcopy::Encoder::new(vec![]);
+/// encoder.write_tsvector(&vector).unwrap();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct PgTsVector {
+    pub lexemes: Vec<PgLexeme>,
+}
+
+impl PgTsVector {
+    /// Creates new text search vector, server sorts lexemes and removes duplicates on its own.
+    pub fn new(lexemes: Vec<PgLexeme>) -> PgTsVector {
+        PgTsVector {
+            lexemes,
+        }
+    }
+}
+
+/// Native `tsquery` value, a text search query tree.
+///
+/// ```edition2018
+/// use pgcopy::types::{PgTsQuery, PgTsWeight};
+///
+/// // 'fat' & ( 'rat' | !'cat':*AB )
+/// let query = PgTsQuery::and(
+///     PgTsQuery::lexeme("fat"),
+///     PgTsQuery::or(
+///         PgTsQuery::lexeme("rat"),
+///         PgTsQuery::not(PgTsQuery::Lexeme {
+///             word: "cat".to_string(),
+///             weights: vec![PgTsWeight::A, PgTsWeight::B],
+///             prefix: true,
+///         }),
+///     ),
+/// );
+///
+/// let mut encoder = pgcopy::Encoder::new(vec![]);
+/// encoder.write_tsquery(&query).unwrap();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PgTsQuery {
+    /// Lexeme operand, matching any of the `weights` (or any weight at all, if empty),
+    /// and matching as a prefix if `prefix` is set.
+    Lexeme {
+        word: String,
+        weights: Vec<PgTsWeight>,
+        prefix: bool,
+    },
+    Not(Box<PgTsQuery>),
+    And(Box<PgTsQuery>, Box<PgTsQuery>),
+    Or(Box<PgTsQuery>, Box<PgTsQuery>),
+    /// Left operand followed by the right one at the exact `distance`, `<->` operator is a distance of `1`.
+    Phrase(Box<PgTsQuery>, Box<PgTsQuery>, u16),
+}
+
+impl PgTsQuery {
+    /// Creates lexeme operand matching any weight.
+    pub fn lexeme<T: Into<String>>(word: T) -> PgTsQuery {
+        PgTsQuery::Lexeme {
+            word: word.into(),
+            weights: Vec::new(),
+            prefix: false,
+        }
+    }
+
+    /// Creates `!operand` query.
+    #[allow(clippy::should_implement_trait)]
+    pub fn not(operand: PgTsQuery) -> PgTsQuery {
+        PgTsQuery::Not(Box::new(operand))
+    }
+
+    /// Creates `left & right` query.
+    pub fn and(left: PgTsQuery, right: PgTsQuery) -> PgTsQuery {
+        PgTsQuery::And(Box::new(left), Box::new(right))
+    }
+
+    /// Creates `left | right` query.
+    pub fn or(left: PgTsQuery, right: PgTsQuery) -> PgTsQuery {
+        PgTsQuery::Or(Box::new(left), Box::new(right))
+    }
+
+    /// Creates `left <distance> right` query.
+    pub fn phrase(left: PgTsQuery, right: PgTsQuery, distance: u16) -> PgTsQuery {
+        PgTsQuery::Phrase(Box::new(left), Box::new(right), distance)
+    }
+
+    fn len(&self) -> usize {
+        match self {
+            PgTsQuery::Lexeme { .. } => 1,
+            PgTsQuery::Not(operand) => 1 + operand.len(),
+            PgTsQuery::And(left, right) | PgTsQuery::Or(left, right) | PgTsQuery::Phrase(left, right, _) => {
+                1 + left.len() + right.len()
+            },
+        }
+    }
+
+    /// Writes query items in the server order: operator, its right operand and then its left operand.
+    fn write_items(&self, buf: &mut Vec<u8>) -> io::Result<()> {
+        let (left, right) = match self {
+            PgTsQuery::Lexeme { word, weights, prefix } => {
+                check_word(word)?;
+
+                buf.write_u8(QI_VAL)?;
+                buf.write_u8(weights.iter().fold(0, |mask, weight| mask | 1 << weight.index()))?;
+                buf.write_u8(*prefix as u8)?;
+                buf.extend_from_slice(word.as_bytes());
+                return buf.write_u8(0);
+            },
+            PgTsQuery::Not(operand) => {
+                buf.write_u8(QI_OPR)?;
+                buf.write_u8(OP_NOT)?;
+                return operand.write_items(buf);
+            },
+            PgTsQuery::And(left, right) => {
+                buf.write_u8(QI_OPR)?;
+                buf.write_u8(OP_AND)?;
+                (left, right)
+            },
+            PgTsQuery::Or(left, right) => {
+                buf.write_u8(QI_OPR)?;
+                buf.write_u8(OP_OR)?;
+                (left, right)
+            },
+            PgTsQuery::Phrase(left, right, distance) => {
+                if *distance > MAXENTRYPOS {
+                    return Err(invalid_input("distance in phrase operator should not be greater than 16383"));
+                }
+
+                buf.write_u8(QI_OPR)?;
+                buf.write_u8(OP_PHRASE)?;
+                buf.write_i16::<NetworkEndian>(*distance as i16)?;
+                (left, right)
+            },
+        };
+
+        right.write_items(buf)?;
+        left.write_items(buf)
+    }
+}
+
+fn invalid_input(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, message)
+}
+
+fn check_word(word: &str) -> io::Result<()> {
+    if word.is_empty() || word.len() > MAXSTRLEN {
+        return Err(invalid_input("lexeme should be from 1 to 2047 bytes long"));
+    }
+    if word.contains('\0') {
+        return Err(invalid_input("lexeme can not contain NUL characters"));
+    }
+
+    Ok(())
+}
+
+fn write_lexeme(buf: &mut Vec<u8>, lexeme: &PgLexeme) -> io::Result<()> {
+    check_word(&lexeme.word)?;
+    if lexeme.positions.len() > MAXNUMPOS {
+        return Err(invalid_input("lexeme can not have more than 256 positions"));
+    }
+
+    buf.extend_from_slice(lexeme.word.as_bytes());
+    buf.write_u8(0)?;
+    buf.write_u16::<NetworkEndian>(lexeme.positions.len() as u16)?;
+    let mut previous = 0;
+    for (position, weight) in &lexeme.positions {
+        if *position == 0 || *position > MAXENTRYPOS {
+            return Err(invalid_input("lexeme position should be in 1..=16383 range"));
+        }
+        if *position <= previous {
+            return Err(invalid_input("lexeme positions should be strictly increasing"));
+        }
+        previous = *position;
+
+        buf.write_u16::<NetworkEndian>(weight.index() << 14 | position)?;
+    }
+
+    Ok(())
+}
+
+fn write_tsvector<W: io::Write>(writer: &mut W, lexemes: &[PgLexeme]) -> io::Result<()> {
+    if lexemes.len() > i32::MAX as usize {
+        return Err(invalid_input("too many lexemes"));
+    }
+
+    write_length_prefixed(writer, |buf| {
+        buf.write_i32::<NetworkEndian>(lexemes.len() as i32)?;
+        for lexeme in lexemes {
+            write_lexeme(buf, lexeme)?;
+        }
+
+        Ok(())
+    })
+}
+
+fn write_tsquery<W: io::Write>(writer: &mut W, query: &PgTsQuery) -> io::Result<()> {
+    let len = query.len();
+    if len > i32::MAX as usize {
+        return Err(invalid_input("tsquery is too large"));
+    }
+
+    write_length_prefixed(writer, |buf| {
+        buf.write_i32::<NetworkEndian>(len as i32)?;
+        query.write_items(buf)
+    })
+}
+
+impl TsVector for PgTsVector {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        write_tsvector(writer, &self.lexemes)
+    }
+}
+
+impl TsVector for &PgTsVector {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        write_tsvector(writer, &self.lexemes)
+    }
+}
+
+impl Element for PgTsVector {
+    const OID: u32 = oid::TSVECTOR;
+
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        TsVector::to_writer(self, writer)
+    }
+}
+
+impl TsQuery for PgTsQuery {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        write_tsquery(writer, self)
+    }
+}
+
+impl TsQuery for &PgTsQuery {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        write_tsquery(writer, self)
+    }
+}
+
+impl Element for PgTsQuery {
+    const OID: u32 = oid::TSQUERY;
+
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        TsQuery::to_writer(self, writer)
+    }
+}
@@ -1,17 +1,201 @@
+use std::io;
+
+use byteorder::{WriteBytesExt, NetworkEndian};
+
+use crate::types::{oid, Element, TimeWithTimeZone, OutOfRange};
+
+/// Microseconds amount in a day, `24:00:00` is a valid time value too.
+const USECS_PER_DAY: i64 = 86_400_000_000;
+
+/// Time zone offsets are required to be less than 16 hours in both directions.
+const TZDISP_LIMIT: i32 = 16 * 60 * 60;
+
+/// Native `time with time zone` value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct PgTimeTz {
+    /// Microseconds amount starting from the `00:00:00`.
+    pub micros: i64,
+    /// Offset from UTC in seconds, positive to the east of UTC, for example, `10800` for `+03:00`.
+    ///
+    /// Note that the server itself keeps offsets to the west of UTC, negated value is written.
+    pub utc_offset: i32,
+}
+
+impl PgTimeTz {
+    /// Creates new time with the UTC offset, in seconds to the east of UTC.
+    pub fn new(micros: i64, utc_offset: i32) -> PgTimeTz {
+        PgTimeTz {
+            micros,
+            utc_offset,
+        }
+    }
+}
+
+fn write_timetz<W: io::Write>(writer: &mut W, micros: i64, utc_offset: i32) -> io::Result<()> {
+    if !(0..=USECS_PER_DAY).contains(&micros) || utc_offset <= -TZDISP_LIMIT || utc_offset >= TZDISP_LIMIT {
+        return Err(OutOfRange::new("time with time zone").into());
+    }
+
+    writer.write_i32::<NetworkEndian>(12)?;
+    writer.write_i64::<NetworkEndian>(micros)?;
+    writer.write_i32::<NetworkEndian>(-utc_offset)
+}
+
+impl TimeWithTimeZone for PgTimeTz {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        write_timetz(writer, self.micros, self.utc_offset)
+    }
+}
+
+impl Element for PgTimeTz {
+    const OID: u32 = oid::TIMETZ;
+
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        TimeWithTimeZone::to_writer(self, writer)
+    }
+}
+
 #[cfg(feature = "with-chrono")]
 mod with_chrono {
-    use std::io;
+    use super::*;
 
-    use chrono::Timelike;
-    use byteorder::{WriteBytesExt, NetworkEndian};
+    use chrono::{DateTime, FixedOffset, NaiveDateTime, NaiveTime, TimeZone, Timelike};
 
     use crate::types::Time;
 
-    impl<T> Time for T where T: Timelike {
+    fn micros<T: Timelike>(time: &T) -> i64 {
+        i64::from(time.num_seconds_from_midnight()) * 1_000 * 1_000 + i64::from(time.nanosecond() / 1_000)
+    }
+
+    fn write_time<W: io::Write, T: Timelike>(writer: &mut W, value: &T) -> io::Result<()> {
+        writer.write_i32::<NetworkEndian>(8)?;
+        writer.write_i64::<NetworkEndian>(micros(value))
+    }
+
+    impl Time for NaiveTime {
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            write_time(writer, self)
+        }
+    }
+
+    impl Time for NaiveDateTime {
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            write_time(writer, self)
+        }
+    }
+
+    impl<Tz: TimeZone> Time for DateTime<Tz> {
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            write_time(writer, self)
+        }
+    }
+
+    impl TimeWithTimeZone for (NaiveTime, FixedOffset) {
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            write_timetz(writer, micros(&self.0), self.1.local_minus_utc())
+        }
+    }
+
+    impl Element for NaiveTime {
+        const OID: u32 = oid::TIME;
+
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            Time::to_writer(self, writer)
+        }
+    }
+
+    impl Element for (NaiveTime, FixedOffset) {
+        const OID: u32 = oid::TIMETZ;
+
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            TimeWithTimeZone::to_writer(self, writer)
+        }
+    }
+}
+
+#[cfg(feature = "with-time")]
+mod with_time {
+    use super::*;
+
+    use time::UtcOffset;
+
+    use crate::types::Time;
+
+    fn micros(time: &time::Time) -> i64 {
+        let seconds = i64::from(time.hour()) * 60 * 60 + i64::from(time.minute()) * 60 + i64::from(time.second());
+        seconds * 1_000 * 1_000 + i64::from(time.microsecond())
+    }
+
+    impl Time for time::Time {
         fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
-            let us = i64::from(self.num_seconds_from_midnight()) * 1_000 * 1_000 + i64::from(self.nanosecond() / 1_000);
             writer.write_i32::<NetworkEndian>(8)?;
-            writer.write_i64::<NetworkEndian>(us)
+            writer.write_i64::<NetworkEndian>(micros(self))
+        }
+    }
+
+    impl TimeWithTimeZone for (time::Time, UtcOffset) {
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            write_timetz(writer, micros(&self.0), self.1.whole_seconds())
+        }
+    }
+
+    impl Element for time::Time {
+        const OID: u32 = oid::TIME;
+
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            Time::to_writer(self, writer)
+        }
+    }
+
+    impl Element for (time::Time, UtcOffset) {
+        const OID: u32 = oid::TIMETZ;
+
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            TimeWithTimeZone::to_writer(self, writer)
+        }
+    }
+}
+
+#[cfg(feature = "with-jiff")]
+mod with_jiff {
+    use super::*;
+
+    use jiff::civil::Time as JiffTime;
+    use jiff::tz::Offset;
+
+    use crate::types::Time;
+
+    fn micros(time: &JiffTime) -> i64 {
+        let seconds = i64::from(time.hour()) * 60 * 60 + i64::from(time.minute()) * 60 + i64::from(time.second());
+        seconds * 1_000 * 1_000 + i64::from(time.subsec_nanosecond() / 1_000)
+    }
+
+    impl Time for JiffTime {
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            writer.write_i32::<NetworkEndian>(8)?;
+            writer.write_i64::<NetworkEndian>(micros(self))
+        }
+    }
+
+    impl TimeWithTimeZone for (JiffTime, Offset) {
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            write_timetz(writer, micros(&self.0), self.1.seconds())
+        }
+    }
+
+    impl Element for JiffTime {
+        const OID: u32 = oid::TIME;
+
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            Time::to_writer(self, writer)
+        }
+    }
+
+    impl Element for (JiffTime, Offset) {
+        const OID: u32 = oid::TIMETZ;
+
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            TimeWithTimeZone::to_writer(self, writer)
         }
     }
 }
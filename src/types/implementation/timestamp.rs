@@ -1,30 +1,292 @@
+use std::io;
+use std::convert::TryFrom;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use byteorder::{WriteBytesExt, NetworkEndian};
+
+use crate::types::{oid, Element, Timestamp, TimestampWithTimeZone, OutOfRange};
+
+/// Unix timestamp of the `2000-01-01T00:00:00+00:00` in microseconds.
+const POSTGRES_EPOCH_UNIX_MICROS: i64 = 946_684_800_000_000;
+
+/// Microseconds amount of the `4714-11-24T00:00:00 BC`, the lowest timestamp supported by server.
+const MIN_TIMESTAMP: i64 = -211_813_488_000_000_000;
+
+/// Microseconds amount of the `294277-01-01T00:00:00`, the first timestamp not supported by server.
+const END_TIMESTAMP: i64 = 9_223_371_331_200_000_000;
+
+/// Writes timestamp from the microseconds amount since `2000-01-01T00:00:00`,
+/// checking it to be in the server supported range.
+pub(crate) fn write_timestamp_micros<W: io::Write>(writer: &mut W, us: i64) -> io::Result<()> {
+    if !(MIN_TIMESTAMP..END_TIMESTAMP).contains(&us) {
+        return Err(OutOfRange::new("timestamp").into());
+    }
+
+    writer.write_i32::<NetworkEndian>(8)?;
+    writer.write_i64::<NetworkEndian>(us)
+}
+
+/// System time is written with the sub-microsecond part truncated,
+/// times before the Unix epoch are rounded down too.
+impl TimestampWithTimeZone for SystemTime {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        let unix_micros = match self.duration_since(UNIX_EPOCH) {
+            Ok(since) => since.as_micros() as i128,
+            Err(err) => {
+                let until = err.duration();
+                let partial = until.subsec_nanos() % 1_000 != 0;
+                -(until.as_micros() as i128) - partial as i128
+            },
+        };
+        let us = i64::try_from(unix_micros - i128::from(POSTGRES_EPOCH_UNIX_MICROS))
+            .map_err(|_| OutOfRange::new("timestamp"))?;
+
+        write_timestamp_micros(writer, us)
+    }
+}
+
+impl Element for SystemTime {
+    const OID: u32 = oid::TIMESTAMPTZ;
+
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        TimestampWithTimeZone::to_writer(self, writer)
+    }
+}
+
+/// Timestamp value extended with the special `infinity` and `-infinity` values.
+///
+/// Wraps any `Timestamp` or `TimestampWithTimeZone` implementor,
+/// which is written as is for the `PgTimestamp::Value` variant.
+///
+/// ```edition2018
+/// # use std::time::SystemTime;
+/// use pgcopy::types::PgTimestamp;
+///
+/// let valid_to: Option<SystemTime> = None;
+///
+/// let mut encoder = pgcopy::Encoder::new(vec![]);
+/// // 'infinity'::timestamptz
+/// encoder.write_timestamp_with_time_zone(PgTimestamp::or_infinity(valid_to)).unwrap();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PgTimestamp<T> {
+    /// Finite timestamp value.
+    Value(T),
+    /// `infinity`, later than all other timestamps.
+    Infinity,
+    /// `-infinity`, earlier than all other timestamps.
+    NegInfinity,
+}
+
+impl<T> PgTimestamp<T> {
+    /// Creates timestamp from the optional value, `None` is mapped to the `infinity`.
+    pub fn or_infinity(value: Option<T>) -> PgTimestamp<T> {
+        value.map_or(PgTimestamp::Infinity, PgTimestamp::Value)
+    }
+
+    /// Creates timestamp from the optional value, `None` is mapped to the `-infinity`.
+    pub fn or_neg_infinity(value: Option<T>) -> PgTimestamp<T> {
+        value.map_or(PgTimestamp::NegInfinity, PgTimestamp::Value)
+    }
+}
+
+impl<T> From<T> for PgTimestamp<T> {
+    fn from(value: T) -> PgTimestamp<T> {
+        PgTimestamp::Value(value)
+    }
+}
+
+fn write_infinite_timestamp<W: io::Write>(writer: &mut W, positive: bool) -> io::Result<()> {
+    writer.write_i32::<NetworkEndian>(8)?;
+    writer.write_i64::<NetworkEndian>(if positive { i64::MAX } else { i64::MIN })
+}
+
+impl<T: Timestamp> Timestamp for PgTimestamp<T> {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        match self {
+            PgTimestamp::Value(value) => value.to_writer(writer),
+            PgTimestamp::Infinity => write_infinite_timestamp(writer, true),
+            PgTimestamp::NegInfinity => write_infinite_timestamp(writer, false),
+        }
+    }
+}
+
+impl<T: TimestampWithTimeZone> TimestampWithTimeZone for PgTimestamp<T> {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        match self {
+            PgTimestamp::Value(value) => value.to_writer(writer),
+            PgTimestamp::Infinity => write_infinite_timestamp(writer, true),
+            PgTimestamp::NegInfinity => write_infinite_timestamp(writer, false),
+        }
+    }
+}
+
+/// Array element of the wrapped value type, use `PgTimestamp<SystemTime>` for the `timestamptz[]` arrays.
+impl<T: Element> Element for PgTimestamp<T> {
+    const OID: u32 = T::OID;
+
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        match self {
+            PgTimestamp::Value(value) => value.to_writer(writer),
+            PgTimestamp::Infinity => write_infinite_timestamp(writer, true),
+            PgTimestamp::NegInfinity => write_infinite_timestamp(writer, false),
+        }
+    }
+}
+
 #[cfg(feature = "with-chrono")]
 mod with_chrono {
-    use std::io;
+    use super::*;
 
     use chrono::{NaiveDateTime, DateTime, TimeZone};
-    use byteorder::{WriteBytesExt, NetworkEndian};
-
-    use crate::types::{Timestamp, TimestampWithTimeZone};
 
     impl Timestamp for NaiveDateTime {
         fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
-            // TODO: Move this big number to constant with an adequate name
-            let us = (self.timestamp_nanos() / 1_000) - 946_684_800_000_000;
-
-            writer.write_i32::<NetworkEndian>(8)?;
-            writer.write_i64::<NetworkEndian>(us)
+            write_timestamp_micros(writer, self.and_utc().timestamp_micros() - POSTGRES_EPOCH_UNIX_MICROS)
         }
     }
 
     impl<Tz: TimeZone> TimestampWithTimeZone for DateTime<Tz> {
         fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
-            // TODO: Move this big number to constant with an adequate name
-            let us = (self.naive_utc().timestamp_nanos() / 1_000) - 946_684_800_000_000;
+            write_timestamp_micros(writer, self.timestamp_micros() - POSTGRES_EPOCH_UNIX_MICROS)
+        }
+    }
 
-            writer.write_i32::<NetworkEndian>(8)?;
-            writer.write_i64::<NetworkEndian>(us)
+    impl Element for NaiveDateTime {
+        const OID: u32 = oid::TIMESTAMP;
+
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            Timestamp::to_writer(self, writer)
         }
+    }
+
+    impl<Tz: TimeZone> Element for DateTime<Tz> {
+        const OID: u32 = oid::TIMESTAMPTZ;
+
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            TimestampWithTimeZone::to_writer(self, writer)
+        }
+    }
+}
+
+#[cfg(feature = "with-time")]
+mod with_time {
+    use std::io;
+
+    use time::{OffsetDateTime, PrimitiveDateTime};
+
+    use crate::types::{oid, Element, Timestamp, TimestampWithTimeZone, OutOfRange};
+    use super::write_timestamp_micros;
 
+    /// Unix timestamp of the `2000-01-01T00:00:00+00:00`.
+    const POSTGRES_EPOCH_UNIX: i64 = 946_684_800;
+
+    fn write_timestamp<W: io::Write>(writer: &mut W, value: OffsetDateTime) -> io::Result<()> {
+        // Microseconds amount may overflow for the years allowed by the `large-dates` feature
+        let us = (value.unix_timestamp() - POSTGRES_EPOCH_UNIX).checked_mul(1_000 * 1_000)
+            .and_then(|us| us.checked_add(i64::from(value.microsecond())))
+            .ok_or_else(|| OutOfRange::new("timestamp"))?;
+
+        write_timestamp_micros(writer, us)
+    }
+
+    impl Timestamp for PrimitiveDateTime {
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            write_timestamp(writer, self.assume_utc())
+        }
+    }
+
+    impl TimestampWithTimeZone for OffsetDateTime {
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            write_timestamp(writer, *self)
+        }
+    }
+
+    impl Element for PrimitiveDateTime {
+        const OID: u32 = oid::TIMESTAMP;
+
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            Timestamp::to_writer(self, writer)
+        }
+    }
+
+    impl Element for OffsetDateTime {
+        const OID: u32 = oid::TIMESTAMPTZ;
+
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            TimestampWithTimeZone::to_writer(self, writer)
+        }
+    }
+}
+
+#[cfg(feature = "with-jiff")]
+mod with_jiff {
+    use std::io;
+
+    use jiff::{Timestamp as JiffTimestamp, Zoned};
+    use jiff::civil::DateTime;
+
+    use crate::types::{oid, Element, Timestamp, TimestampWithTimeZone};
+    use super::write_timestamp_micros;
+
+    /// Unix timestamp of the `2000-01-01T00:00:00+00:00` in nanoseconds.
+    const POSTGRES_EPOCH_UNIX_NANOS: i128 = 946_684_800_000_000_000;
+
+    /// Writes timestamp from the nanoseconds amount since `2000-01-01T00:00:00`,
+    /// sub-microsecond part is truncated.
+    fn write_timestamp<W: io::Write>(writer: &mut W, nanos: i128) -> io::Result<()> {
+        // Supported range of years is -9999..=9999, so microseconds always fit
+        write_timestamp_micros(writer, nanos.div_euclid(1_000) as i64)
+    }
+
+    impl Timestamp for DateTime {
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            let epoch = DateTime::constant(2000, 1, 1, 0, 0, 0, 0);
+
+            write_timestamp(writer, self.duration_since(epoch).as_nanos())
+        }
+    }
+
+    impl TimestampWithTimeZone for JiffTimestamp {
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            write_timestamp(writer, self.as_nanosecond() - POSTGRES_EPOCH_UNIX_NANOS)
+        }
+    }
+
+    /// Zoned datetime is converted to UTC, time zone itself is not preserved.
+    impl TimestampWithTimeZone for Zoned {
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            TimestampWithTimeZone::to_writer(&self.timestamp(), writer)
+        }
+    }
+
+    impl TimestampWithTimeZone for &Zoned {
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            TimestampWithTimeZone::to_writer(&self.timestamp(), writer)
+        }
+    }
+
+    impl Element for DateTime {
+        const OID: u32 = oid::TIMESTAMP;
+
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            Timestamp::to_writer(self, writer)
+        }
+    }
+
+    impl Element for JiffTimestamp {
+        const OID: u32 = oid::TIMESTAMPTZ;
+
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            TimestampWithTimeZone::to_writer(self, writer)
+        }
+    }
+
+    impl Element for Zoned {
+        const OID: u32 = oid::TIMESTAMPTZ;
+
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            TimestampWithTimeZone::to_writer(self, writer)
+        }
     }
 }
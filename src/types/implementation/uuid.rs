@@ -2,8 +2,7 @@ use std::io;
 
 use byteorder::{WriteBytesExt, NetworkEndian};
 
-use crate::types::Uuid as UuidExt;
-
+use crate::types::{oid, Element, Uuid as UuidExt};
 
 impl UuidExt for [u8; 16] {
     fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
@@ -12,6 +11,14 @@ impl UuidExt for [u8; 16] {
     }
 }
 
+impl Element for [u8; 16] {
+    const OID: u32 = oid::UUID;
+
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        UuidExt::to_writer(self, writer)
+    }
+}
+
 #[cfg(feature = "with-uuid")]
 mod with_uuid {
     use super::*;
@@ -24,4 +31,12 @@ mod with_uuid {
             writer.write_all(self.as_bytes())
         }
     }
+
+    impl Element for Uuid {
+        const OID: u32 = oid::UUID;
+
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            UuidExt::to_writer(self, writer)
+        }
+    }
 }
@@ -0,0 +1,108 @@
+use std::io;
+
+use byteorder::{WriteBytesExt, NetworkEndian};
+
+use crate::types::Xml;
+
+fn write_xml<W: io::Write>(writer: &mut W, value: &str) -> io::Result<()> {
+    debug_assert!(value.len() < i32::MAX as usize);
+
+    writer.write_i32::<NetworkEndian>(value.len() as i32)?;
+    writer.write_all(value.as_bytes())
+}
+
+impl Xml for &str {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        write_xml(writer, self)
+    }
+}
+
+impl Xml for String {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        write_xml(writer, self)
+    }
+}
+
+#[cfg(feature = "with-quick-xml")]
+mod with_quick_xml {
+    use super::*;
+
+    use quick_xml::Reader;
+    use quick_xml::events::{BytesStart, Event};
+
+    use crate::types::{oid, Element};
+
+    /// Wrapper for the XML content, which is checked to be well-formed before being written.
+    ///
+    /// Malformed content is rejected with an error, instead of failing the whole `COPY` on the server side.
+    /// Multiple root elements and top-level text are allowed, as the default `xmloption = content` setting does.
+    ///
+    /// Check is a best-effort one: it catches unbalanced or mismatched tags, malformed or duplicate attributes
+    /// and unknown entity references, but unlike the server `libxml2` parser it does not validate
+    /// element and attribute names, namespace prefixes, characters not allowed by the XML specification
+    /// or `DOCTYPE` declarations, so some values passing the check still can be rejected by the server.
+    ///
+    /// ```edition2018
+    /// use pgcopy::types::PgCheckedXml;
+    ///
+    /// let mut encoder = pgcopy::Encoder::new(vec![]);
+    /// encoder.write_xml(PgCheckedXml("<book><title>Manual</title></book>")).unwrap();
+    /// assert!(encoder.write_xml(PgCheckedXml("<book><title>Manual</book>")).is_err());
+    /// ```
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct PgCheckedXml<T>(pub T);
+
+    fn malformed<E: std::fmt::Display>(err: E) -> io::Error {
+        io::Error::new(io::ErrorKind::InvalidInput, format!("malformed XML: {}", err))
+    }
+
+    fn check_attributes(tag: &BytesStart) -> io::Result<()> {
+        for attribute in tag.attributes() {
+            attribute.map_err(malformed)?.unescape_value().map_err(malformed)?;
+        }
+
+        Ok(())
+    }
+
+    fn check(value: &str) -> io::Result<()> {
+        let mut reader = Reader::from_str(value);
+        let mut open_tags = 0usize;
+        loop {
+            match reader.read_event().map_err(malformed)? {
+                Event::Start(tag) => {
+                    check_attributes(&tag)?;
+                    open_tags += 1;
+                },
+                Event::Empty(tag) => check_attributes(&tag)?,
+                // Reader itself rejects end tags not matching the open ones
+                Event::End(_) => open_tags -= 1,
+                Event::Text(text) => {
+                    text.unescape().map_err(malformed)?;
+                },
+                Event::Eof if open_tags > 0 => return Err(malformed("start tag is not closed")),
+                Event::Eof => return Ok(()),
+                _ => {},
+            }
+        }
+    }
+
+    impl<T: AsRef<str>> Xml for PgCheckedXml<T> {
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            let value = self.0.as_ref();
+            check(value)?;
+
+            write_xml(writer, value)
+        }
+    }
+
+    impl<T: AsRef<str>> Element for PgCheckedXml<T> {
+        const OID: u32 = oid::XML;
+
+        fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+            Xml::to_writer(self, writer)
+        }
+    }
+}
+
+#[cfg(feature = "with-quick-xml")]
+pub use self::with_quick_xml::PgCheckedXml;
@@ -2,17 +2,111 @@
 //!
 //! Trait implementations should properly write bytes into supplied writer
 //! according to PostgreSQL binary format.
+//!
+//! All the column type traits are implemented for `Option` of their implementors too,
+//! `None` value is written as `NULL`.
 
 use std::io;
 
-#[doc(hidden)]
-pub trait Numeric {}
+pub mod oid;
+
+/// Trait for `smallint` type implementations.
+///
+/// Implementors should write:
+/// 1. signed 4 bytes of the following data length, value is required to be `2`
+/// 2. signed 2 bytes of the value
+pub trait SmallInt {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()>;
+}
+
+/// Trait for `int` type implementations.
+///
+/// Implementors should write:
+/// 1. signed 4 bytes of the following data length, value is required to be `4`
+/// 2. signed 4 bytes of the value
+pub trait Int {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()>;
+}
+
+/// Trait for `bigint` type implementations.
+///
+/// Implementors should write:
+/// 1. signed 4 bytes of the following data length, value is required to be `8`
+/// 2. signed 8 bytes of the value
+pub trait BigInt {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()>;
+}
+
+/// Trait for `numeric` (also known as `decimal`) type implementations.
+///
+/// Implementors should write:
+/// 1. signed 4 bytes of the following data length, value is required to be `8 + 2 * ndigits`
+/// 2. signed 2 bytes of the base-10000 digits amount (`ndigits`)
+/// 3. signed 2 bytes of the weight of the first digit, expressed as a power of 10000
+/// 4. signed 2 bytes of the sign: `0x0000` for positive values, `0x4000` for negative values,
+///    `0xC000` for `NaN`, `0xD000` for `Infinity` and `0xF000` for `-Infinity`
+/// 5. signed 2 bytes of the display scale, amount of the decimal digits after the decimal point
+/// 6. `ndigits` signed 2 bytes base-10000 digits, starting from the most significant one
+///
+/// Note that `Infinity` and `-Infinity` values are supported by PostgreSQL 14 and above only.
+pub trait Numeric {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()>;
+}
+
+/// Trait for `real` type implementations.
+///
+/// Implementors should write:
+/// 1. signed 4 bytes of the following data length, value is required to be `4`
+/// 2. 4 bytes float of the value
+pub trait Real {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()>;
+}
+
+/// Trait for `double precision` type implementations.
+///
+/// Implementors should write:
+/// 1. signed 4 bytes of the following data length, value is required to be `8`
+/// 2. 8 bytes float of the value
+pub trait Double {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()>;
+}
+
+/// Trait for `money` type implementations.
+///
+/// Implementors should write:
+/// 1. signed 4 bytes of the following data length, value is required to be `8`
+/// 2. signed 8 bytes of the amount in the smallest currency units
+///
+/// Amount of the fractional digits depends on the server `lc_monetary` setting.
+pub trait Money {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()>;
+}
+
+/// Trait for character types implementations.
+///
+/// Implementors should write:
+/// 1. signed 4 bytes of the following data length
+/// 2. UTF-8 text bytes
+pub trait Text {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()>;
+}
+
+/// Trait for `bytea` type implementations.
+///
+/// Implementors should write:
+/// 1. signed 4 bytes of the following data length
+/// 2. raw bytes
+pub trait Bytea {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()>;
+}
 
 /// Trait for `timestamp` type implementations.
 ///
 /// Implementors should write:
 /// 1. signed 4 bytes of the following data length, value is required to be `8`
 /// 2. signed 8 bytes of the timestamp, expressed as a microseconds amount from `2000-01-01T00:00:00+00:00`.
+///
+/// Timestamps outside of the `4714-11-24T00:00:00 BC..294277-01-01T00:00:00` range should be rejected with `OutOfRange` error.
 pub trait Timestamp {
     fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()>;
 }
@@ -24,6 +118,7 @@ pub trait Timestamp {
 /// 2. signed 8 bytes of the timestamp, expressed as a microseconds amount from `2000-01-01T00:00:00+00:00`.
 ///
 /// Datetime with some timezone specified should be converted into datetime with UTC timezone before.
+/// Same as for the `Timestamp`, values out of the server supported range should be rejected with `OutOfRange` error.
 pub trait TimestampWithTimeZone {
     fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()>;
 }
@@ -33,6 +128,8 @@ pub trait TimestampWithTimeZone {
 /// Implementors should write:
 /// 1. signed 4 bytes of the following data length, value is required to be `4`
 /// 2. signed 4 bytes of the date, expressed as a days amount from `2000-01-01`.
+///
+/// Dates outside of the `4714-11-24 BC..5874898-01-01` range should be rejected with `OutOfRange` error.
 pub trait Date {
     fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()>;
 }
@@ -46,14 +143,158 @@ pub trait Time {
     fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()>;
 }
 
-#[doc(hidden)]
-pub trait Interval {}
+/// Trait for `time with time zone` type implementations.
+///
+/// Implementors should write:
+/// 1. signed 4 bytes of the following data length, value is required to be `12`
+/// 2. signed 8 bytes of the time, expressed as a microseconds amount starting from the `00:00:00`
+/// 3. signed 4 bytes of the time zone offset in seconds, positive to the *west* of UTC,
+///    for example, `-10800` for `+03:00`
+pub trait TimeWithTimeZone {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()>;
+}
+
+/// Trait for `interval` type implementations.
+///
+/// Implementors should write:
+/// 1. signed 4 bytes of the following data length, value is required to be `16`
+/// 2. signed 8 bytes of the microseconds amount
+/// 3. signed 4 bytes of the days amount
+/// 4. signed 4 bytes of the months amount
+///
+/// See [PgInterval](struct.PgInterval.html) for a native value with all the parts available.
+pub trait Interval {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()>;
+}
+
+/// Trait for `bool` type implementations.
+///
+/// Implementors should write:
+/// 1. signed 4 bytes of the following data length, value is required to be `1`
+/// 2. `1` byte for `true` and `0` byte for `false`
+pub trait Bool {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()>;
+}
+
+/// Trait for `point` type implementations.
+///
+/// Implementors should write:
+/// 1. signed 4 bytes of the following data length, value is required to be `16`
+/// 2. 8 bytes float of the `x` coordinate
+/// 3. 8 bytes float of the `y` coordinate
+pub trait Point {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()>;
+}
+
+/// Trait for `line` type implementations.
+///
+/// Implementors should write:
+/// 1. signed 4 bytes of the following data length, value is required to be `24`
+/// 2. 8 bytes floats of the `A`, `B` and `C` coefficients of the `Ax + By + C = 0` linear equation
+pub trait Line {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()>;
+}
+
+/// Trait for `lseg` type implementations.
+///
+/// Implementors should write:
+/// 1. signed 4 bytes of the following data length, value is required to be `32`
+/// 2. two points of the segment ends, each one as a 8 bytes floats of the `x` and `y` coordinates
+pub trait LineSegment {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()>;
+}
+
+/// Trait for `box` type implementations.
+///
+/// Implementors should write:
+/// 1. signed 4 bytes of the following data length, value is required to be `32`
+/// 2. upper right corner point, as a 8 bytes floats of the `x` and `y` coordinates
+/// 3. lower left corner point, as a 8 bytes floats of the `x` and `y` coordinates
+pub trait Rectangle {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()>;
+}
+
+/// Trait for `path` type implementations.
+///
+/// Implementors should write:
+/// 1. signed 4 bytes of the following data length
+/// 2. 1 byte of the flag, `1` for closed path and `0` for open one
+/// 3. signed 4 bytes of the points amount, at least one point is required
+/// 4. all the points, each one as a 8 bytes floats of the `x` and `y` coordinates
+pub trait Path {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()>;
+}
+
+/// Trait for `polygon` type implementations.
+///
+/// Implementors should write:
+/// 1. signed 4 bytes of the following data length
+/// 2. signed 4 bytes of the vertices amount, at least one vertex is required
+/// 3. all the vertices, each one as a 8 bytes floats of the `x` and `y` coordinates
+pub trait Polygon {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()>;
+}
 
-#[doc(hidden)]
-pub trait Cidr {}
+/// Trait for `circle` type implementations.
+///
+/// Implementors should write:
+/// 1. signed 4 bytes of the following data length, value is required to be `24`
+/// 2. center point, as a 8 bytes floats of the `x` and `y` coordinates
+/// 3. 8 bytes float of the radius, which is not allowed to be negative
+pub trait Circle {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()>;
+}
 
-#[doc(hidden)]
-pub trait Inet {}
+/// Trait for PostGIS `geometry` and `geography` types implementations.
+///
+/// Implementors should write:
+/// 1. signed 4 bytes of the following data length
+/// 2. geometry in the Extended Well-Known Binary (EWKB) format, optionally with the SRID
+///
+/// Server rejects line strings with one point only and polygons with non-closed rings
+/// or rings with less than four points.
+pub trait Geometry {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()>;
+}
+
+/// Trait for `cidr` type implementations.
+///
+/// Implementors should write:
+/// 1. signed 4 bytes of the following data length, value is required to be `8` for IPv4 and `20` for IPv6
+/// 2. 1 byte of the address family, `2` for IPv4 and `3` for IPv6
+/// 3. 1 byte of the netmask length in bits
+/// 4. 1 byte of the `cidr` flag, value is required to be `1`
+/// 5. 1 byte of the address length, `4` for IPv4 and `16` for IPv6
+/// 6. 4 or 16 bytes of the network address
+///
+/// Network address should have no bits set to the right of the netmask, otherwise server rejects it.
+pub trait Cidr {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()>;
+}
+
+/// Trait for `inet` type implementations.
+///
+/// Implementors should write:
+/// 1. signed 4 bytes of the following data length, value is required to be `8` for IPv4 and `20` for IPv6
+/// 2. 1 byte of the address family, `2` for IPv4 and `3` for IPv6
+/// 3. 1 byte of the netmask length in bits
+/// 4. 1 byte of the `cidr` flag, value is required to be `0`
+/// 5. 1 byte of the address length, `4` for IPv4 and `16` for IPv6
+/// 6. 4 or 16 bytes of the host address
+pub trait Inet {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()>;
+}
+
+/// Trait for enumerated (`enum`) type implementations.
+///
+/// Implementors should write:
+/// 1. signed 4 bytes of the following data length
+/// 2. enum label text
+///
+/// Enable `derive` feature to derive this trait implementation for Rust enums with `PgEnum` derive macro.
+pub trait Enum {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()>;
+}
 
 /// Trait for `macaddr` type implementations.
 ///
@@ -73,6 +314,53 @@ pub trait MacAddr8 {
     fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()>;
 }
 
+/// Trait for `bit` type implementations.
+///
+/// Implementors should write:
+/// 1. signed 4 bytes of the following data length
+/// 2. signed 4 bytes of the bits amount
+/// 3. bits packed into bytes, starting from the most significant bit, with padding bits set to zero
+///
+/// Bits amount is required to match the column length exactly.
+pub trait Bit {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()>;
+}
+
+/// Trait for `bit varying` type implementations.
+///
+/// Implementors should write data in the same format as the [Bit](trait.Bit.html) type does.
+pub trait VarBit {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()>;
+}
+
+/// Trait for `tsvector` type implementations.
+///
+/// Implementors should write:
+/// 1. signed 4 bytes of the following data length
+/// 2. signed 4 bytes of the lexemes amount
+/// 3. each lexeme as a NUL-terminated string, followed by unsigned 2 bytes of the positions amount
+///    and the positions themselves, each one as unsigned 2 bytes with the weight in the two highest bits
+///    (`3` for `A` down to `0` for `D`) and the position in the rest of them
+///
+/// Positions of each lexeme are required to be strictly increasing.
+pub trait TsVector {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()>;
+}
+
+/// Trait for `tsquery` type implementations.
+///
+/// Implementors should write:
+/// 1. signed 4 bytes of the following data length
+/// 2. signed 4 bytes of the query items amount
+/// 3. query items in the prefix order, with the right operand of each operator preceding its left operand:
+///    * operand as a `1` byte, followed by 1 byte of the weights bitmask (`8` for `A` down to `1` for `D`),
+///      1 byte of the prefix match flag and the lexeme as a NUL-terminated string
+///    * operator as a `2` byte, followed by 1 byte of the operator kind (`1` for `NOT`, `2` for `AND`,
+///      `3` for `OR` and `4` for `PHRASE`), phrase operator is followed by signed 2 bytes of the distance
+pub trait TsQuery {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()>;
+}
+
 /// Trait for `uuid` type implementations.
 ///
 /// Implementors should write:
@@ -82,13 +370,120 @@ pub trait Uuid {
     fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()>;
 }
 
-#[doc(hidden)]
-pub trait Xml {}
+/// Trait for `xml` type implementations.
+///
+/// Implementors should write:
+/// 1. signed 4 bytes of the following data length
+/// 2. XML content in the encoding from its XML declaration, or in UTF-8 if there is none
+///
+/// Malformed content is rejected by the server, failing the whole `COPY` operation.
+pub trait Xml {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()>;
+}
+
+/// Trait for `json` type implementations.
+///
+/// Implementors should write:
+/// 1. signed 4 bytes of the following data length
+/// 2. JSON document text
+pub trait Json {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()>;
+}
+
+/// Trait for `jsonb` type implementations.
+///
+/// Implementors should write:
+/// 1. signed 4 bytes of the following data length, including the version byte
+/// 2. 1 byte of the `jsonb` format version, value is required to be `1`
+/// 3. JSON document text
+pub trait Jsonb {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()>;
+}
+
+/// Trait for types which can be written as an element of the container types, like arrays.
+///
+/// Implementors should write element the same way as it is written for a column value:
+/// 1. signed 4 bytes of the following data length, or `-1` for `NULL` value
+/// 2. value bytes according to the element type binary format
+///
+/// Element type [OID](oid/index.html) is required to be provided too,
+/// as server checks if it matches the column element type.
+pub trait Element {
+    /// OID of the element type.
+    const OID: u32;
+
+    /// Returns `true` if value should be written as a `NULL`.
+    fn is_null(&self) -> bool {
+        false
+    }
+
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()>;
+}
+
+/// Trait for array type implementations.
+///
+/// Implementors should write:
+/// 1. signed 4 bytes of the following data length
+/// 2. signed 4 bytes of the dimensions amount, `0` for an empty array
+/// 3. signed 4 bytes of the flags, value is required to be `1` if there are `NULL` elements and `0` otherwise
+/// 4. 4 bytes of the element type OID
+/// 5. signed 4 bytes of the size and signed 4 bytes of the lower bound for each dimension
+/// 6. all the elements, see [Element](trait.Element.html) trait for details
+///
+/// See [PgArray](struct.PgArray.html) for multi-dimensional arrays and arrays with custom lower bounds.
+pub trait Array {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()>;
+}
+
+/// Trait for composite (row) type implementations.
+///
+/// Implementors should write:
+/// 1. signed 4 bytes of the following data length
+/// 2. signed 4 bytes of the fields amount
+/// 3. 4 bytes of the field type OID, followed by the field value for each field,
+///    see [Element](trait.Element.html) trait for details
+///
+/// See [PgComposite](struct.PgComposite.html) for a field by field composite value builder.
+pub trait Composite {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()>;
+}
 
-#[doc(hidden)]
-pub trait Json {}
+/// Trait for range type implementations.
+///
+/// Implementors should write:
+/// 1. signed 4 bytes of the following data length
+/// 2. 1 byte of the flags: `0x01` for an empty range, `0x02` and `0x04` for the inclusive lower and upper bounds,
+///    `0x08` and `0x10` for the infinite lower and upper bounds
+/// 3. lower bound value, unless range is empty or lower bound is infinite
+/// 4. upper bound value, unless range is empty or upper bound is infinite
+///
+/// Bound values are written the same way as an array elements,
+/// see [Element](trait.Element.html) trait for details.
+pub trait Range {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()>;
+}
 
-#[doc(hidden)]
-pub trait Jsonb {}
+/// Trait for multirange type implementations.
+///
+/// Implementors should write:
+/// 1. signed 4 bytes of the following data length
+/// 2. signed 4 bytes of the ranges amount
+/// 3. all the ranges, see [Range](trait.Range.html) trait for details
+///
+/// Multirange types are supported by PostgreSQL 14 and above only.
+pub trait Multirange {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()>;
+}
 
 mod implementation;
+
+pub use self::implementation::{OutOfRange, PgNumeric};
+pub use self::implementation::{PgTimestamp, PgDate, PgTimeTz, PgInterval, PgArray, Dimension, PgComposite, PgRange, PgMultirange};
+pub use self::implementation::{PgPoint, PgLine, PgLineSegment, PgBox, PgPath, PgPolygon, PgCircle};
+pub use self::implementation::{PgCoord, PgGeometry, PgSrid};
+pub use self::implementation::{PgBitString, PgMoney, PgJsonb};
+pub use self::implementation::{PgTsWeight, PgLexeme, PgTsVector, PgTsQuery};
+#[cfg(feature = "with-serde_json")]
+pub use self::implementation::PgJson;
+#[cfg(feature = "with-quick-xml")]
+pub use self::implementation::PgCheckedXml;